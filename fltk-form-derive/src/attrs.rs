@@ -0,0 +1,162 @@
+use proc_macro2::{Span, TokenStream};
+use quote::*;
+use syn::spanned::Spanned;
+use syn::*;
+
+/// A single `key` or `key = value` of a `#[form(...)]` attribute
+pub struct FormArg {
+    pub name: String,
+    pub value: Option<Lit>,
+    pub span: Span,
+}
+
+impl FormArg {
+    pub fn error(&self, msg: &str) -> Error {
+        Error::new(self.span, msg)
+    }
+
+    pub fn unknown(&self) -> Error {
+        self.error(&format!("unknown form attribute `{}`", self.name))
+    }
+
+    pub fn str(&self) -> Result<String> {
+        match &self.value {
+            Some(Lit::Str(s)) => Ok(s.value()),
+            _ => Err(self.error(&format!("expected `{} = \"...\"`", self.name))),
+        }
+    }
+
+    pub fn int(&self) -> Result<usize> {
+        match &self.value {
+            Some(Lit::Int(i)) => i.base10_parse(),
+            _ => Err(self.error(&format!("expected `{} = <integer>`", self.name))),
+        }
+    }
+}
+
+pub fn form_args(attrs: &[Attribute]) -> Result<Vec<FormArg>> {
+    let mut args = vec![];
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("form")) {
+        let list = match attr.parse_meta()? {
+            Meta::List(list) => list,
+            other => return Err(Error::new_spanned(other, "expected `#[form(...)]`")),
+        };
+        for nested in list.nested {
+            let (path, value) = match nested {
+                NestedMeta::Meta(Meta::Path(path)) => (path, None),
+                NestedMeta::Meta(Meta::NameValue(nv)) => (nv.path, Some(nv.lit)),
+                other => return Err(Error::new_spanned(other, "expected `key` or `key = value`")),
+            };
+            let name = match path.get_ident() {
+                Some(ident) => ident.to_string(),
+                None => return Err(Error::new_spanned(path, "expected an identifier")),
+            };
+            args.push(FormArg {
+                name,
+                value,
+                span: path.span(),
+            });
+        }
+    }
+    Ok(args)
+}
+
+/// Options of `#[form(...)]` on a struct
+#[derive(Default)]
+pub struct StructOpts {
+    pub layout: Option<TokenStream>,
+}
+
+impl StructOpts {
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut layout = None;
+        let mut columns = None;
+        for arg in form_args(attrs)? {
+            match arg.name.as_str() {
+                "layout" => layout = Some((arg.str()?, arg.span)),
+                "columns" => columns = Some(arg.int()?),
+                _ => return Err(arg.unknown()),
+            }
+        }
+        let layout = match (layout, columns) {
+            (None, None) => None,
+            (Some((l, _)), None) if l == "inline" => {
+                Some(quote!(fltk_form::FormLayout::LabelsInline))
+            }
+            (Some((l, _)), None) if l == "top" => Some(quote!(fltk_form::FormLayout::LabelsOnTop)),
+            (Some((l, _)), Some(n)) if l == "grid" => Some(quote!(fltk_form::FormLayout::Grid(#n))),
+            (None, Some(n)) => Some(quote!(fltk_form::FormLayout::Grid(#n))),
+            (Some((_, span)), _) => {
+                return Err(Error::new(
+                    span,
+                    "expected `layout = \"inline\"`, `layout = \"top\"` or `layout = \"grid\", columns = N`",
+                ))
+            }
+        };
+        Ok(Self { layout })
+    }
+}
+
+/// Options of `#[form(...)]` on a struct field
+#[derive(Default)]
+pub struct FieldOpts {
+    pub tab: Option<String>,
+    pub page: Option<usize>,
+    pub page_span: Option<Span>,
+    /// A predicate on the other fields, like `mode == Mode::Advanced`
+    pub visible_if: Option<(String, Span)>,
+    pub enabled_if: Option<(String, Span)>,
+    /// A `Vec` shown as a table, like `FlTable`
+    pub table: bool,
+    /// A `String` shown as multiline text, like `FlText`
+    pub multiline: bool,
+    /// The maximum number of characters of multiline text
+    pub max_len: Option<usize>,
+    /// A `String` shown masked, like `FlSecret`
+    pub secret: bool,
+    /// The patterns of the files a path is chosen among, like `*.toml`
+    pub filter: Option<String>,
+    /// A path to a directory rather than a file
+    pub directory: bool,
+    /// A path saved to, rather than opened
+    pub save: bool,
+    /// A path which must be there to be opened, or saved to
+    pub exists: bool,
+}
+
+impl FieldOpts {
+    /// Whether the field is shown as multiline text
+    pub fn is_text(&self) -> bool {
+        self.multiline || self.max_len.is_some()
+    }
+
+    /// Whether the field is a path chosen with other than the default options, like `FlPath`
+    pub fn is_path(&self) -> bool {
+        self.filter.is_some() || self.directory || self.save || self.exists
+    }
+
+    pub fn parse(attrs: &[Attribute]) -> Result<Self> {
+        let mut opts = Self::default();
+        for arg in form_args(attrs)? {
+            match arg.name.as_str() {
+                "tab" => opts.tab = Some(arg.str()?),
+                "page" => {
+                    opts.page = Some(arg.int()?);
+                    opts.page_span = Some(arg.span);
+                }
+                "visible_if" => opts.visible_if = Some((arg.str()?, arg.span)),
+                "enabled_if" => opts.enabled_if = Some((arg.str()?, arg.span)),
+                "table" => opts.table = true,
+                "multiline" => opts.multiline = true,
+                "max_len" => opts.max_len = Some(arg.int()?),
+                "secret" => opts.secret = true,
+                "filter" => opts.filter = Some(arg.str()?),
+                "directory" => opts.directory = true,
+                "save" => opts.save = true,
+                "exists" => opts.exists = true,
+                _ => return Err(arg.unknown()),
+            }
+        }
+        Ok(opts)
+    }
+}
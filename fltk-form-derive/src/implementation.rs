@@ -1,5 +1,6 @@
+use crate::attrs::*;
 use proc_macro::TokenStream;
-use proc_macro2::Span;
+use proc_macro2::{Span, TokenStream as TokenStream2};
 use quote::*;
 use syn::*;
 
@@ -21,7 +22,22 @@ pub fn impl_widget_deser_trait(ast: &DeriveInput) -> Result<TokenStream> {
                         }
                     }
                 });
+                let variant_names = variants.variants.iter().map(|field| {
+                    let field_name = &field.ident;
+                    LitStr::new(&field_name.to_string(), field_name.span())
+                });
+                let variant_idents = variants.variants.iter().map(|field| &field.ident);
+                let variant_names_v = variant_names.clone();
+                let variant_idents_v = variant_idents.clone();
                 let data_expanded_members_c = data_expanded_members.clone();
+                let data_expanded_members_v = data_expanded_members.clone();
+                let blank = match variants.variants.first() {
+                    Some(first) => {
+                        let first = &first.ident;
+                        quote!(Some(#name::#first))
+                    }
+                    None => quote!(None),
+                };
                 gen = quote! {
                     impl FltkForm for #name {
                         fn generate(&self) -> Box<dyn WidgetExt> {
@@ -31,13 +47,36 @@ pub fn impl_widget_deser_trait(ast: &DeriveInput) -> Result<TokenStream> {
                                 choice.add_choice(mem);
                             }
                             choice.set_value(*self as i32);
+                            fltk_form::register_accessor(&choice, fltk_form::ValueAccessor::choice());
                             Box::new(choice)
                         }
                         fn view(&self) -> Box<dyn WidgetExt> {
                             let mut choice = output::Output::default();
                             choice.set_value(&format!("{:?}", *self));
+                            fltk_form::register_accessor(
+                                &choice,
+                                fltk_form::ValueAccessor {
+                                    check: |_, value| [#(#data_expanded_members_v),*].contains(&value),
+                                    ..fltk_form::ValueAccessor::text::<output::Output, String>()
+                                },
+                            );
                             Box::new(choice)
                         }
+                        fn from_widget(wid: &fltk::widget::Widget) -> Option<Self> {
+                            match fltk_form::value_of(wid)?.as_str() {
+                                #(#variant_names => Some(#name::#variant_idents),)*
+                                _ => None,
+                            }
+                        }
+                        fn blank() -> Option<Self> {
+                            #blank
+                        }
+                    }
+
+                    impl fltk_form::FormVariants for #name {
+                        fn variants() -> Vec<(&'static str, Self)> {
+                            vec![#((#variant_names_v, #name::#variant_idents_v)),*]
+                        }
                     }
                 };
             }
@@ -46,59 +85,194 @@ pub fn impl_widget_deser_trait(ast: &DeriveInput) -> Result<TokenStream> {
                 fields: Fields::Named(it),
                 ..
             }) => {
-                let data_expanded_members_gen = it.named.iter().map(|field| {
-                    let field_name = field.ident.as_ref().expect("Unreachable");
-                    let span = field_name.span();
-                    let field_name_stringified = LitStr::new(&field_name.to_string(), span);
-                    quote_spanned! {
-                        span => {
-                            let mut i = self.#field_name.generate();
-                            i.set_align(fltk::enums::Align::Left);
-                            i.set_label(#field_name_stringified);
+                let opts = it
+                    .named
+                    .iter()
+                    .map(|field| FieldOpts::parse(&field.attrs))
+                    .collect::<Result<Vec<_>>>()?;
+                if let (Some(paged), Some(_)) = (
+                    opts.iter().find_map(|opts| opts.page_span),
+                    opts.iter().find(|opts| opts.tab.is_some()),
+                ) {
+                    return Err(Error::new(
+                        paged,
+                        "`page` and `tab` can't be used in the same struct",
+                    ));
+                }
+                // fields without a tab come first, then those of each tab in order of appearance,
+                // which is the order of their widgets. Fields without a page are on page 0.
+                let mut tabs: Vec<&String> = vec![];
+                for tab in opts.iter().filter_map(|opts| opts.tab.as_ref()) {
+                    if !tabs.contains(&tab) {
+                        tabs.push(tab);
+                    }
+                }
+                let mut order: Vec<usize> = (0..it.named.len())
+                    .filter(|&i| opts[i].tab.is_none())
+                    .collect();
+                for tab in &tabs {
+                    order.extend(
+                        (0..it.named.len()).filter(|&i| opts[i].tab.as_ref() == Some(*tab)),
+                    );
+                }
+                let paged = opts.iter().any(|opts| opts.page.is_some());
+                order.sort_by_key(|&i| opts[i].page.unwrap_or(0));
+                let fields: Vec<&Field> = it.named.iter().collect();
+                let conditions = opts
+                    .iter()
+                    .map(|opts| {
+                        let visible = opts
+                            .visible_if
+                            .as_ref()
+                            .map(|p| condition("visible_if", p, &fields, &order))
+                            .transpose()?;
+                        let enabled = opts
+                            .enabled_if
+                            .as_ref()
+                            .map(|p| condition("enabled_if", p, &fields, &order))
+                            .transpose()?;
+                        Ok(quote!(#visible #enabled))
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+                let expand_members = |method: Ident| {
+                    let mut current_tab = None;
+                    let mut current_page = None;
+                    order
+                        .iter()
+                        .map(|&i| {
+                            let field = &it.named[i];
+                            let field_name = field.ident.as_ref().expect("Unreachable");
+                            let span = field_name.span();
+                            let field_name_stringified = LitStr::new(&field_name.to_string(), span);
+                            let tab = match &opts[i].tab {
+                                Some(tab) if current_tab != Some(tab) => {
+                                    current_tab = Some(tab);
+                                    Some(quote_spanned!(span => f.tab(#tab);))
+                                }
+                                _ => None,
+                            };
+                            let page = opts[i].page.unwrap_or(0);
+                            let page = if paged && current_page != Some(page) {
+                                current_page = Some(page);
+                                Some(quote_spanned!(span => f.page(#page);))
+                            } else {
+                                None
+                            };
+                            let condition = &conditions[i];
+                            let widget = if opts[i].table {
+                                let method = format_ident!("{}_rows", method);
+                                quote_spanned!(span => fltk_form::FlTable::#method(&self.#field_name))
+                            } else if opts[i].secret {
+                                let method = format_ident!("{}_secret", method);
+                                quote_spanned!(span => fltk_form::FlSecret::#method(AsRef::<str>::as_ref(&self.#field_name)))
+                            } else if opts[i].is_text() {
+                                let text = quote_spanned!(span => AsRef::<str>::as_ref(&self.#field_name));
+                                if method == "generate" {
+                                    let max_len = match opts[i].max_len {
+                                        Some(max_len) => quote!(Some(#max_len)),
+                                        None => quote!(None),
+                                    };
+                                    quote_spanned!(span => fltk_form::FlText::generate_text(#text, #max_len))
+                                } else {
+                                    quote_spanned!(span => fltk_form::FlText::view_text(#text))
+                                }
+                            } else if opts[i].is_path() {
+                                let method = format_ident!("{}_path", method);
+                                let filter = match &opts[i].filter {
+                                    Some(filter) => quote!(Some(String::from(#filter))),
+                                    None => quote!(None),
+                                };
+                                let (directory, save, exists) =
+                                    (opts[i].directory, opts[i].save, opts[i].exists);
+                                quote_spanned! {
+                                    span => fltk_form::FlPath::#method(
+                                        AsRef::<std::path::Path>::as_ref(&self.#field_name),
+                                        fltk_form::PathOptions {
+                                            filter: #filter,
+                                            directory: #directory,
+                                            save: #save,
+                                            exists: #exists,
+                                        },
+                                    )
+                                }
+                            } else {
+                                quote_spanned!(span => self.#field_name.#method())
+                            };
+                            quote_spanned! {
+                                span => #tab #page f.add(#field_name_stringified, #widget); #condition
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                };
+                let data_expanded_members_gen =
+                    expand_members(Ident::new("generate", Span::call_site()));
+                let data_expanded_members_view =
+                    expand_members(Ident::new("view", Span::call_site()));
+                let with_layout = StructOpts::parse(&ast.attrs)?
+                    .layout
+                    .map(|layout| quote!(.with_layout(#layout)));
+                let field_names = it.named.iter().map(|field| &field.ident);
+                let field_readers = it.named.iter().zip(opts.iter()).map(|(field, opts)| {
+                    let ty = &field.ty;
+                    if opts.table {
+                        quote!(fltk_form::FlTable::rows_from_widget)
+                    } else if opts.secret {
+                        quote! {
+                            (|w: &fltk::widget::Widget| {
+                                fltk_form::FlSecret::secret_from_widget(w).map(<#ty>::from)
+                            })
                         }
+                    } else if opts.is_text() {
+                        quote! {
+                            (|w: &fltk::widget::Widget| {
+                                fltk_form::FlText::text_from_widget(w).map(<#ty>::from)
+                            })
+                        }
+                    } else if opts.is_path() {
+                        quote! {
+                            (|w: &fltk::widget::Widget| {
+                                fltk_form::FlPath::path_from_widget(w).map(<#ty>::from)
+                            })
+                        }
+                    } else {
+                        quote!(<#ty as FltkForm>::from_widget)
                     }
                 });
-                let data_expanded_members_view = it.named.iter().map(|field| {
-                    let field_name = field.ident.as_ref().expect("Unreachable");
-                    let span = field_name.span();
-                    let field_name_stringified = LitStr::new(&field_name.to_string(), span);
-                    quote_spanned! {
-                        span => {
-                            let mut i = self.#field_name.view();
-                            i.set_align(fltk::enums::Align::Left);
-                            i.set_label(#field_name_stringified);
-                        }
+                let blank_names = it.named.iter().map(|field| &field.ident);
+                let blanks = it.named.iter().zip(opts.iter()).map(|(field, opts)| {
+                    let ty = &field.ty;
+                    if opts.secret || opts.is_text() {
+                        quote!(<#ty>::from(String::new()))
+                    } else if opts.is_path() {
+                        quote!(<#ty>::from(std::path::PathBuf::new()))
+                    } else {
+                        quote!(<#ty as FltkForm>::blank()?)
                     }
                 });
+                let field_indices =
+                    (0..it.named.len()).map(|i| order.iter().position(|&j| j == i).unwrap_or(i));
                 gen = quote! {
                     impl FltkForm for #name {
                         fn generate(&self) -> Box<dyn WidgetExt> {
-                            let mut p = group::Pack::default()
-                                .with_label(&format!("{}", #name_str))
-                                .with_align(fltk::enums::Align::Left | fltk::enums::Align::Top);
-                            p.set_spacing(5);
-                            let mems = vec![#(#data_expanded_members_gen),*];
-                            p.end();
-                            let parent = p.parent().unwrap();
-                            p.resize(
-                                parent.x() + (parent.width()/2), parent.y() + parent.h() / 9, parent.width() / 3, (mems.len() * 30 + 5 * mems.len()) as i32
-                            );
-                            p.auto_layout();
-                            Box::new(p)
+                            let mut f = fltk_form::FieldsBuilder::new(#name_str)#with_layout;
+                            #(#data_expanded_members_gen)*
+                            f.finish()
                         }
                         fn view(&self) -> Box<dyn WidgetExt> {
-                            let mut p = group::Pack::default()
-                                .with_label(&format!("{}", #name_str))
-                                .with_align(fltk::enums::Align::Left | fltk::enums::Align::Top);
-                            p.set_spacing(5);
-                            let mems = vec![#(#data_expanded_members_view),*];
-                            p.end();
-                            let parent = p.parent().unwrap();
-                            p.resize(
-                                parent.x() + (parent.width()/2), parent.y() + parent.h() / 9, parent.width() / 3, (mems.len() * 30 + 5 * mems.len()) as i32
-                            );
-                            p.auto_layout();
-                            Box::new(p)
+                            let mut f = fltk_form::FieldsBuilder::new(#name_str)#with_layout;
+                            #(#data_expanded_members_view)*
+                            f.finish()
+                        }
+                        fn from_widget(wid: &fltk::widget::Widget) -> Option<Self> {
+                            let fields = fltk_form::fields_of(wid);
+                            Some(#name {
+                                #(#field_names: #field_readers(fields.get(#field_indices)?)?,)*
+                            })
+                        }
+                        fn blank() -> Option<Self> {
+                            Some(#name {
+                                #(#blank_names: #blanks,)*
+                            })
                         }
                     }
                 };
@@ -114,3 +288,60 @@ pub fn impl_widget_deser_trait(ast: &DeriveInput) -> Result<TokenStream> {
         gen.into()
     })
 }
+
+/// The single identifiers used as values in a predicate, like `mode` in `mode == Mode::Advanced`
+/// or `name` in `name.is_empty()`, which are the fields it may read
+#[derive(Default)]
+struct Variables(Vec<String>);
+
+impl<'ast> visit::Visit<'ast> for Variables {
+    fn visit_expr_path(&mut self, expr: &'ast ExprPath) {
+        if expr.qself.is_none() {
+            if let Some(ident) = expr.path.get_ident() {
+                self.0.push(ident.to_string());
+            }
+        }
+        visit::visit_expr_path(self, expr);
+    }
+}
+
+/// The `f.visible_if(...)` or `f.enabled_if(...)` call of a predicate on the fields of a struct,
+/// `order` being the order in which the widgets of the fields are added.
+/// The fields named in the predicate are read back from their widget and bound to their name.
+fn condition(
+    method: &str,
+    (predicate, span): &(String, Span),
+    fields: &[&Field],
+    order: &[usize],
+) -> Result<TokenStream2> {
+    let expr: Expr = parse_str(predicate)
+        .map_err(|err| Error::new(*span, format!("invalid `{}`: {}", method, err)))?;
+    let mut variables = Variables::default();
+    visit::Visit::visit_expr(&mut variables, &expr);
+    let idents = variables.0;
+    let deps: Vec<usize> = (0..fields.len())
+        .filter(|&i| {
+            fields[i]
+                .ident
+                .as_ref()
+                .is_some_and(|ident| idents.contains(&ident.to_string()))
+        })
+        .collect();
+    let positions: Vec<usize> = deps
+        .iter()
+        .map(|&i| order.iter().position(|&j| j == i).unwrap_or(i))
+        .collect();
+    let names = deps.iter().map(|&i| &fields[i].ident);
+    let types = deps.iter().map(|&i| &fields[i].ty);
+    let types_c = types.clone();
+    let method = Ident::new(method, *span);
+    Ok(quote_spanned! {
+        *span => f.#method(&[#(#positions),*], |fields: &[fltk::widget::Widget]| {
+            #(
+                #[allow(unused_variables)]
+                let #names: #types = <#types_c as FltkForm>::from_widget(fields.get(#positions)?)?;
+            )*
+            Some(#expr)
+        });
+    })
+}
@@ -3,13 +3,14 @@ extern crate proc_macro2;
 extern crate quote;
 extern crate syn;
 
+mod attrs;
 mod implementation;
 use crate::implementation::*;
 
 use proc_macro::TokenStream;
 
-#[proc_macro_derive(FltkForm)]
+#[proc_macro_derive(FltkForm, attributes(form))]
 pub fn deser_widget_trait_macro(input: TokenStream) -> TokenStream {
     let ast = syn::parse(input).unwrap();
-    impl_widget_deser_trait(&ast).unwrap()
+    impl_widget_deser_trait(&ast).unwrap_or_else(|e| e.to_compile_error().into())
 }
@@ -43,8 +43,7 @@ fn main() {
         .with_size(300, 200)
         .center_of_parent();
 
-    let mut form = my_struct.generate(); // <-- generate the form
-    form.resize(form.x() - 50, form.y(), form.w() + 30, form.h());
+    let form = my_struct.generate(); // <-- generate the form
 
     grp.end();
     grp.set_frame(enums::FrameType::EngravedFrame);
@@ -58,7 +57,7 @@ fn main() {
 
     let v = form.get_prop("b"); // <-- get a single property
     assert_eq!(v, Some("3.0".to_owned()));
-    
+
     let mut c = form.get_widget("c").unwrap();
     c.set_color(Color::Red.inactive());
 
@@ -0,0 +1,36 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FlColor, FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Theme {
+    name: String,
+    background: FlColor,
+    accent: enums::Color,
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 300);
+    let mut form = Form::new(0, 0, 400, 260, None).from_data(Theme {
+        name: String::from("sunset"),
+        background: FlColor(String::from("lightsalmon")), // <-- HTML names or #rrggbb
+        accent: enums::Color::from_rgb(200, 40, 80),
+    });
+    let mut btn = button::Button::new(290, 265, 100, 30, "Print");
+    win.end();
+    win.show();
+
+    form.set_prop("accent", "steelblue").unwrap();
+
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("background")); // <-- read back as #rrggbb
+        println!("{:?}", form.get_data::<Theme>());
+    });
+
+    a.run().unwrap();
+}
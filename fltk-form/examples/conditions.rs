@@ -0,0 +1,46 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+
+#[derive(Copy, Debug, Clone, PartialEq, FltkForm)]
+pub enum Mode {
+    Simple,
+    Advanced,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Settings {
+    mode: Mode,
+    use_proxy: bool,
+    #[form(visible_if = "use_proxy")] // <-- shown while use_proxy is checked
+    proxy_host: String,
+    #[form(visible_if = "use_proxy", enabled_if = "mode == Mode::Advanced")]
+    proxy_port: u16,
+    #[form(enabled_if = "mode == Mode::Advanced")]
+    retries: u8,
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 300);
+    let mut form = Form::new(0, 0, 400, 300, None).from_data(Settings {
+        mode: Mode::Simple,
+        use_proxy: false,
+        proxy_host: String::from("localhost"),
+        proxy_port: 8080,
+        retries: 3,
+    });
+    win.end();
+    win.show();
+
+    form.on_change("retries", |w| {
+        println!("retries: {:?}", fltk_form::value_of(w))
+    })
+    .unwrap();
+
+    a.run().unwrap();
+}
@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::FltkForm;
+
+#[derive(Copy, Debug, Clone, FltkForm)]
+pub enum MyEnum {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct MyStruct {
+    a: f64,
+    b: f64,
+    c: String,
+    d: MyEnum,
+    e: bool,
+}
+
+impl Default for MyStruct {
+    fn default() -> Self {
+        Self {
+            a: 0.0,
+            b: 3.0,
+            c: String::from("fltk-rs"),
+            d: MyEnum::A,
+            e: true,
+        }
+    }
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 300);
+    let mut btn = button::Button::default()
+        .with_label("edit")
+        .with_size(80, 30)
+        .center_of_parent();
+    win.end();
+    win.show();
+
+    let mut my_struct = MyStruct::default();
+    btn.set_callback(move |_| {
+        // <-- blocks until OK or Cancel is pressed
+        if let Some(edited) = fltk_form::edit_dialog(&my_struct, "Edit MyStruct") {
+            my_struct = edited;
+            fltk_form::view_dialog(&my_struct, "Result");
+        }
+    });
+
+    a.run().unwrap();
+}
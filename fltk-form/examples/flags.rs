@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use bitflags::bitflags;
+use fltk::{prelude::*, *};
+use fltk_form::{FlFlags, FltkForm, Form};
+
+bitflags! {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    pub struct Mode: u8 {
+        const READ = 0b001;
+        const WRITE = 0b010;
+        const EXEC = 0b100;
+    }
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct File {
+    path: String,
+    mode: FlFlags<Mode>, // <-- a checkbox per named flag
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 200);
+    let form = Form::new(0, 0, 400, 160, None).from_data(File {
+        path: String::from("/tmp/a.txt"),
+        mode: FlFlags(Mode::READ | Mode::WRITE),
+    });
+    let mut btn = button::Button::new(290, 165, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("mode")); // <-- like [READ, WRITE]
+        println!("{:#?}", form.get_data::<File>());
+    });
+
+    a.run().unwrap();
+}
@@ -1,5 +1,5 @@
 use fltk::{prelude::*, *};
-use fltk_form::{FltkForm, HasProps};
+use fltk_form::{FieldsBuilder, FltkForm, HasProps};
 
 #[derive(Debug, Clone)]
 pub struct MyStruct<T> {
@@ -20,66 +20,26 @@ impl<T: Copy + Default + FltkForm> MyStruct<T> {
 
 impl<T: Copy + Default + FltkForm> FltkForm for MyStruct<T> {
     fn generate(&self) -> Box<dyn WidgetExt> {
-        let mut p = group::Pack::default()
-            .with_label(&format!("{}", "MyStruct"))
-            .with_align(fltk::enums::Align::Left | fltk::enums::Align::Top);
-        p.set_spacing(5);
-        let mut i = self.a.generate();
-        {
-            i.set_align(fltk::enums::Align::Left);
-            i.set_label("a");
-        }
-        let mut i = self.b.generate();
-        {
-            i.set_align(fltk::enums::Align::Left);
-            i.set_label("b");
-        }
-        let mut i = self.c.generate();
-        {
-            i.set_align(fltk::enums::Align::Left);
-            i.set_label("c");
-        }
-        p.end();
-        let parent = p.parent().unwrap();
-        p.resize(
-            parent.x() + (parent.width() / 2),
-            parent.y() + parent.h() / 9,
-            parent.width() / 3,
-            (3 * 30 + 5 * 3) as i32,
-        );
-        p.auto_layout();
-        Box::new(p)
+        let mut f = FieldsBuilder::new("MyStruct");
+        f.add("a", self.a.generate());
+        f.add("b", self.b.generate());
+        f.add("c", self.c.generate());
+        f.finish()
     }
     fn view(&self) -> Box<dyn WidgetExt> {
-        let mut p = group::Pack::default()
-            .with_label(&format!("{}", "MyStruct"))
-            .with_align(fltk::enums::Align::Left | fltk::enums::Align::Top);
-        p.set_spacing(5);
-        let mut i = self.a.view();
-        {
-            i.set_align(fltk::enums::Align::Left);
-            i.set_label("a");
-        }
-        let mut i = self.b.view();
-        {
-            i.set_align(fltk::enums::Align::Left);
-            i.set_label("b");
-        }
-        let mut i = self.c.view();
-        {
-            i.set_align(fltk::enums::Align::Left);
-            i.set_label("c");
-        }
-        p.end();
-        let parent = p.parent().unwrap();
-        p.resize(
-            parent.x() + (parent.width() / 2),
-            parent.y() + parent.h() / 9,
-            parent.width() / 3,
-            (3 * 30 + 5 * 3) as i32,
-        );
-        p.auto_layout();
-        Box::new(p)
+        let mut f = FieldsBuilder::new("MyStruct");
+        f.add("a", self.a.view());
+        f.add("b", self.b.view());
+        f.add("c", self.c.view());
+        f.finish()
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        let fields = fltk_form::fields_of(wid);
+        Some(Self {
+            a: T::from_widget(fields.first()?)?,
+            b: f64::from_widget(fields.get(1)?)?,
+            c: String::from_widget(fields.get(2)?)?,
+        })
     }
 }
 
@@ -94,8 +54,7 @@ fn main() {
         .with_size(300, 200)
         .center_of_parent();
 
-    let mut form = my_struct.generate(); // <-- generate the form
-    form.resize(form.x() - 50, form.y(), form.w() + 30, form.h());
+    let form = my_struct.generate(); // <-- generate the form
 
     grp.end();
     grp.set_frame(enums::FrameType::EngravedFrame);
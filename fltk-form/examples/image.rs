@@ -30,8 +30,7 @@ fn main() {
         .with_size(300, 200)
         .center_of_parent();
 
-    let mut form = my_struct.generate(); // <-- generate the form
-    form.resize(form.x() - 50, form.y(), form.w() + 30, form.h());
+    let _form = my_struct.generate(); // <-- generate the form
 
     grp.end();
     grp.set_frame(enums::FrameType::EngravedFrame);
@@ -0,0 +1,74 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form, FormLayout};
+
+#[derive(Copy, Debug, Clone, FltkForm)]
+pub enum MyEnum {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+#[form(layout = "grid", columns = 2)] // <-- 2 fields per row
+pub struct Address {
+    street: String,
+    number: u32,
+    city: String,
+    zip: String,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct MyStruct {
+    name: String,
+    age: u8,
+    choice: MyEnum,
+    address: Address,
+    subscribed: bool,
+}
+
+impl Default for MyStruct {
+    fn default() -> Self {
+        Self {
+            name: String::from("fltk-rs"),
+            age: 3,
+            choice: MyEnum::B,
+            address: Address {
+                street: String::from("Main street"),
+                number: 1,
+                city: String::from("Springfield"),
+                zip: String::from("12345"),
+            },
+            subscribed: true,
+        }
+    }
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 500);
+    let mut form = Form::new(0, 0, 500, 460, None)
+        .with_layout(FormLayout::LabelsOnTop) // <-- applies to structs without their own layout
+        .from_data(MyStruct::default());
+    let mut choice = menu::Choice::new(200, 465, 100, 30, None);
+    choice.add_choice("Inline|On top|Grid");
+    choice.set_value(1);
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    choice.set_callback(move |c| {
+        let layout = match c.value() {
+            0 => FormLayout::LabelsInline,
+            1 => FormLayout::LabelsOnTop,
+            _ => FormLayout::Grid(2),
+        };
+        form.set_layout(layout);
+    });
+
+    a.run().unwrap();
+}
@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+use std::collections::{BTreeMap, HashMap};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Config {
+    env: HashMap<String, String>, // <-- shown sorted by key
+    gains: BTreeMap<String, f64>, // <-- in key order
+    ports: BTreeMap<u16, String>, // <-- a key which isn't a u16 or is used twice turns red
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 500);
+    let form = Form::new(0, 0, 500, 460, None).from_data(Config {
+        env: HashMap::from([
+            (String::from("RUST_LOG"), String::from("info")),
+            (String::from("HOME"), String::from("/home/user")),
+        ]),
+        gains: BTreeMap::from([(String::from("left"), 0.8), (String::from("right"), 1.2)]),
+        ports: BTreeMap::from([(80, String::from("http")), (443, String::from("https"))]),
+    });
+    let mut btn = button::Button::new(390, 465, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("gains")); // <-- like {left: 0.8, right: 1.2}
+        println!("{:?}", form.get_prop("ports[443]"));
+        match form.validate() {
+            Ok(()) => println!("{:#?}", form.get_data::<Config>()),
+            Err(e) => println!("{}", e),
+        }
+    });
+
+    a.run().unwrap();
+}
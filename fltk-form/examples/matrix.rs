@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Transform {
+    position: [f64; 3],      // <-- a row of 3 inputs
+    rotation: [[f64; 3]; 3], // <-- a fixed 3x3 grid
+    weights: Vec<Vec<i32>>,  // <-- rows and columns are inserted next to the focused cell
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 500);
+    let mut form = Form::new(0, 0, 500, 460, None).from_data(Transform {
+        position: [0.0, 1.5, -2.0],
+        rotation: [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]],
+        weights: vec![vec![1, 2], vec![3, 4]],
+    });
+    let mut btn = button::Button::new(390, 465, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    form.set_prop("rotation[1]", "[0, 0, 1]").ok();
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("weights")); // <-- like [[1, 2], [3, 4]]
+        println!("{:?}", form.get_prop("rotation[1][2]"));
+        println!("{:#?}", form.get_data::<Transform>());
+    });
+
+    a.run().unwrap();
+}
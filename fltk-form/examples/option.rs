@@ -0,0 +1,39 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Config {
+    name: String,
+    timeout: Option<u32>, // <-- a checkbox enables the input
+    proxy: Option<String>,
+    verbose: Option<bool>, // <-- a choice between None, true and false
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 300);
+    let mut form = Form::new(0, 0, 400, 260, None).from_data(Config {
+        name: String::from("server"),
+        timeout: Some(30),
+        proxy: None,
+        verbose: None,
+    });
+    let mut btn = button::Button::new(290, 265, 100, 30, "Print");
+    win.end();
+    win.show();
+
+    form.set_prop("proxy", "Some()").unwrap(); // <-- set, but empty
+    assert_eq!(form.get_prop("proxy"), Some(String::from("Some()")));
+    assert_eq!(form.get_prop("verbose"), Some(String::from("None")));
+
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_data::<Config>());
+    });
+
+    a.run().unwrap();
+}
@@ -0,0 +1,43 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FlPath, FltkForm, Form};
+use std::path::PathBuf;
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Project {
+    #[form(filter = "*.toml", exists)]
+    manifest: PathBuf, // <-- must be an existing file
+    #[form(directory, exists)]
+    sources: PathBuf, // <-- must be an existing directory
+    #[form(filter = "*.log", save, exists)]
+    log: PathBuf, // <-- needn't exist, but its directory must
+    readme: FlPath, // <-- any path
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 250);
+    let form = Form::new(0, 0, 500, 210, None).from_data(Project {
+        manifest: PathBuf::from("Cargo.toml"),
+        sources: PathBuf::from("src"),
+        log: PathBuf::from("target/build.log"),
+        readme: FlPath::from("README.md"),
+    });
+    let mut btn = button::Button::new(390, 215, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    btn.set_callback(move |_| {
+        match form.validate() {
+            Ok(()) => println!("{:#?}", form.get_data::<Project>()),
+            Err(e) => println!("{}", e), // <-- the paths which aren't there
+        }
+    });
+
+    a.run().unwrap();
+}
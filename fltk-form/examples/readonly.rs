@@ -0,0 +1,53 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+
+#[derive(Copy, Debug, Clone, FltkForm)]
+pub enum Plan {
+    Free,
+    Pro,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Account {
+    name: String,
+    email: String,
+    plan: Plan,
+    seats: u8,
+    newsletter: bool,
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 300);
+    let mut form = Form::new(0, 0, 400, 260, None).from_data(Account {
+        name: String::from("fltk-rs"),
+        email: String::from("fltk@rs.org"),
+        plan: Plan::Free,
+        seats: 1,
+        newsletter: true,
+    });
+    let mut edit = button::CheckButton::new(10, 265, 80, 30, "Edit");
+    edit.set_value(true);
+    let mut pro = button::CheckButton::new(100, 265, 80, 30, "Pro");
+    win.end();
+    win.show();
+
+    form.set_visible("seats", false).unwrap(); // <-- the rows below move up
+    form.set_enabled("newsletter", false).unwrap();
+
+    edit.set_callback({
+        let mut form = form.clone();
+        move |b| form.set_form_readonly(!b.value()) // <-- no regeneration
+    });
+    pro.set_callback(move |b| {
+        form.set_visible("seats", b.value()).unwrap();
+        form.set_readonly("plan", b.value()).unwrap();
+    });
+
+    a.run().unwrap();
+}
@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FlSecret, FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Account {
+    user: String,
+    password: FlSecret, // <-- masked, and printed as ******** by Debug
+    #[form(secret)]
+    api_key: String, // <-- masked as well
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 250);
+    let form = Form::new(0, 0, 400, 210, None).from_data(Account {
+        user: String::from("alice"),
+        password: FlSecret::from("hunter2"),
+        api_key: String::from("sk-0123456789"),
+    });
+    let mut btn = button::Button::new(290, 215, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_props()); // <-- only the user
+        println!("{:?}", form.get_prop("api_key")); // <-- asked for by name
+        println!("{:?}", form.get_data::<Account>().map(|a| a.password));
+    });
+
+    a.run().unwrap();
+}
@@ -0,0 +1,69 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Address {
+    street: String,
+    city: String,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Contact {
+    email: String,
+    phone: String,
+    address: Address,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct MyStruct {
+    name: String,
+    contact: Contact, // <-- nested structs are shown as collapsible sections
+    billing: Address,
+}
+
+impl Default for MyStruct {
+    fn default() -> Self {
+        let address = Address {
+            street: String::from("Main street"),
+            city: String::from("Springfield"),
+        };
+        Self {
+            name: String::from("fltk-rs"),
+            contact: Contact {
+                email: String::from("fltk@rs"),
+                phone: String::new(),
+                address: address.clone(),
+            },
+            billing: address,
+        }
+    }
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 400);
+    let mut form = Form::new(0, 0, 400, 360, None).from_data(MyStruct::default());
+    form.set_collapsed("billing", true).unwrap(); // <-- click a section's title to toggle it
+    let mut btn = button::Button::new(150, 365, 100, 30, "Refresh");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    btn.set_callback(move |_| {
+        // the collapsed sections are kept when the widgets are generated again
+        if let Err(e) = form.refresh() {
+            println!("{}", e);
+        }
+        println!(
+            "contact.address collapsed: {}",
+            form.is_collapsed("contact.address")
+        );
+    });
+
+    a.run().unwrap();
+}
@@ -0,0 +1,59 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+use std::collections::{BTreeSet, HashSet};
+
+#[derive(Copy, Debug, Clone, PartialEq, Eq, Hash, FltkForm)]
+pub enum Permission {
+    Read,
+    Write,
+    Execute,
+}
+
+#[derive(Copy, Debug, Clone, PartialEq, Eq, PartialOrd, Ord, FltkForm)]
+pub enum Feature {
+    Audio,
+    Video,
+    Network,
+    Storage,
+    Camera,
+    Location,
+    Bluetooth,
+    Contacts,
+    Calendar,
+    Sensors,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct User {
+    name: String,
+    permissions: HashSet<Permission>, // <-- a checkbox per variant
+    features: BTreeSet<Feature>,      // <-- too many variants, a multi-select browser
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 400);
+    let mut form = Form::new(0, 0, 500, 360, None).from_data(User {
+        name: String::from("alice"),
+        permissions: HashSet::from([Permission::Read]),
+        features: BTreeSet::from([Feature::Audio, Feature::Network]),
+    });
+    let mut btn = button::Button::new(390, 365, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    form.set_prop("permissions", "[Read, Write]").ok();
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("permissions")); // <-- like [Read, Write]
+        println!("{:?}", form.get_prop("permissions.Execute"));
+        println!("{:#?}", form.get_data::<User>());
+    });
+
+    a.run().unwrap();
+}
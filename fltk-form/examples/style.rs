@@ -0,0 +1,57 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form, FormStyle};
+
+#[derive(Copy, Debug, Clone, FltkForm)]
+pub enum MyEnum {
+    A,
+    B,
+    C,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct MyStruct {
+    name: String,
+    age: u8,
+    choice: MyEnum,
+    subscribed: bool,
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+
+    let mut win = window::Window::default().with_size(400, 300);
+    let form = Form::new(0, 0, 400, 260, None)
+        .with_style(FormStyle::dark().with_label_color("lightsteelblue")) // <-- before the data
+        .from_data(MyStruct {
+            name: String::from("fltk-rs"),
+            age: 3,
+            choice: MyEnum::B,
+            subscribed: true,
+        });
+    let mut dark = button::CheckButton::new(10, 265, 100, 30, "Dark");
+    dark.set_value(true);
+    let mut check = button::Button::new(290, 265, 100, 30, "Validate");
+    win.end();
+    win.show();
+
+    dark.set_callback({
+        let mut form = form.clone();
+        move |b| {
+            // restyles the widgets already generated
+            form.set_style(if b.value() {
+                FormStyle::dark()
+            } else {
+                FormStyle::light()
+            });
+        }
+    });
+    check.set_callback(move |_| {
+        // invalid fields get the error color
+        println!("{:?}", form.validate());
+    });
+
+    a.run().unwrap();
+}
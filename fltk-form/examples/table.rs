@@ -0,0 +1,60 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FlTable, FltkForm, Form};
+
+#[derive(Copy, Debug, Clone, FltkForm)]
+pub enum Role {
+    Member,
+    Admin,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Contact {
+    name: String,
+    age: u8,
+    role: Role,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Team {
+    name: String,
+    members: FlTable<Contact>, // <-- click a cell to edit it, a header to sort
+    #[form(table)]
+    alumni: Vec<Contact>, // <-- the same for a Vec
+}
+
+fn contact(name: &str, age: u8, role: Role) -> Contact {
+    Contact {
+        name: name.to_string(),
+        age,
+        role,
+    }
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 600);
+    let form = Form::new(0, 0, 500, 560, None).from_data(Team {
+        name: String::from("fltk-rs"),
+        members: FlTable(vec![
+            contact("Alice", 34, Role::Admin),
+            contact("Bob", 27, Role::Member),
+            contact("Carol", 41, Role::Member),
+        ]),
+        alumni: vec![contact("Dave", 52, Role::Member)],
+    });
+    let mut btn = button::Button::new(390, 565, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    btn.set_callback(move |_| {
+        println!("{:#?}", form.get_data::<Team>());
+    });
+
+    a.run().unwrap();
+}
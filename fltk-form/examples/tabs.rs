@@ -0,0 +1,61 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Proxy {
+    host: String,
+    port: u16,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Settings {
+    name: String,
+    #[form(tab = "Network")] // <-- fields with the same tab share a page
+    server: String,
+    #[form(tab = "Network")]
+    timeout: u32,
+    #[form(tab = "Network")]
+    proxy: Proxy,
+    #[form(tab = "Display")]
+    fullscreen: bool,
+    #[form(tab = "Display")]
+    scale: f32,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            name: String::from("fltk-rs"),
+            server: String::from("localhost"),
+            timeout: 30,
+            proxy: Proxy {
+                host: String::new(),
+                port: 8080,
+            },
+            fullscreen: false,
+            scale: 1.0,
+        }
+    }
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 400);
+    let form = Form::new(0, 0, 400, 360, None).from_data(Settings::default());
+    let mut btn = button::Button::new(150, 365, 100, 30, "print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_props()); // <-- the fields of every tab
+        println!("{:?}", form.get_data::<Settings>());
+    });
+
+    a.run().unwrap();
+}
@@ -0,0 +1,38 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FlText, FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Post {
+    title: String,
+    body: FlText, // <-- wrapped multiline text
+    #[form(multiline, max_len = 140)]
+    summary: String, // <-- the same for a String, with a character counter
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 400);
+    let mut form = Form::new(0, 0, 500, 360, None).from_data(Post {
+        title: String::from("Hello"),
+        body: FlText::from("First line\nSecond line"),
+        summary: String::new(),
+    });
+    let mut btn = button::Button::new(390, 365, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    form.set_prop("summary", "A short post\nover two lines")
+        .ok();
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("body"));
+        println!("{:#?}", form.get_data::<Post>());
+    });
+
+    a.run().unwrap();
+}
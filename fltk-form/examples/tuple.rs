@@ -0,0 +1,37 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Form};
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Sprite {
+    name: String,
+    position: (f64, f64),    // <-- two inputs on one row
+    range: (u32, u32, bool), // <-- the elements are named range.0, range.1 and range.2
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(500, 300);
+    let mut form = Form::new(0, 0, 500, 260, None).from_data(Sprite {
+        name: String::from("player"),
+        position: (10.0, 20.0),
+        range: (1, 100, true),
+    });
+    let mut btn = button::Button::new(390, 265, 100, 30, "Print");
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    form.set_prop("position.1", "42.5").ok();
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("position")); // <-- like (10.0, 42.5)
+        println!("{:?}", form.get_prop("range.1"));
+        println!("{:#?}", form.get_data::<Sprite>());
+    });
+
+    a.run().unwrap();
+}
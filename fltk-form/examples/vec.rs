@@ -2,11 +2,11 @@
 extern crate fltk_form_derive;
 
 use fltk::{prelude::*, *};
-use fltk_form::FltkForm;
+use fltk_form::{FltkForm, HasProps};
 
 #[derive(Copy, Debug, Clone, FltkForm)]
 pub enum MyEnum {
-    A,
+    A, // <-- the first variant, added by the list's + button as the blank value
     B,
     C,
 }
@@ -37,12 +37,21 @@ fn main() {
         .with_size(300, 200)
         .center_of_parent();
 
-    let mut form = my_struct.generate(); // <-- generate the form
-    form.resize(grp.x() + 30, grp.y() - 30, grp.w() - 60, grp.h());
+    let form = my_struct.generate(); // <-- generate the form
 
     grp.end();
+    let mut btn = button::Button::default()
+        .with_label("print")
+        .with_size(80, 30)
+        .below_of(&grp, 5)
+        .center_x(&grp);
     win.end();
     win.show();
 
+    btn.set_callback(move |_| {
+        println!("{:?}", form.get_prop("choices")); // <-- reflects added and deleted items
+        println!("{:?}", form.get_prop("choices[0]"));
+    });
+
     a.run().unwrap();
 }
@@ -0,0 +1,47 @@
+#[macro_use]
+extern crate fltk_form_derive;
+
+use fltk::{prelude::*, *};
+use fltk_form::{FltkForm, Wizard};
+
+#[derive(Copy, Debug, Clone, FltkForm)]
+pub enum Plan {
+    Free,
+    Pro,
+}
+
+#[derive(Debug, Clone, FltkForm)]
+pub struct Onboarding {
+    name: String, // <-- fields without a page are on page 0
+    email: String,
+    #[form(page = 1)]
+    age: u8,
+    #[form(page = 1)]
+    plan: Plan,
+    #[form(page = 2)]
+    newsletter: bool,
+}
+
+fn main() {
+    let a = app::App::default().with_scheme(app::Scheme::Gtk);
+    app::set_background_color(222, 222, 222);
+
+    let mut win = window::Window::default().with_size(400, 300);
+    let mut wizard = Wizard::new(0, 0, 400, 300, None).from_data(Onboarding {
+        name: String::new(),
+        email: String::new(),
+        age: 18,
+        plan: Plan::Free,
+        newsletter: false,
+    });
+    win.end();
+    win.make_resizable(true);
+    win.show();
+
+    wizard.on_finish(move |data: Onboarding| {
+        println!("{:?}", data);
+        app::quit();
+    });
+
+    a.run().unwrap();
+}
@@ -0,0 +1,95 @@
+use crate::{FltkForm, FltkFormError, Form};
+use fltk::{prelude::*, *};
+use std::cell::Cell;
+use std::rc::Rc;
+
+const WIDTH: i32 = 400;
+const HEIGHT: i32 = 300;
+
+#[derive(Clone, Copy)]
+enum Answer {
+    Accept,
+    Reject,
+}
+
+fn make_dialog<F: FnOnce() -> Box<dyn WidgetExt>>(
+    title: &str,
+    gen: F,
+    buttons: &[&'static str],
+) -> (window::Window, Form, Rc<Cell<Option<Answer>>>) {
+    let answer = Rc::new(Cell::new(None));
+    let mut win = window::Window::default()
+        .with_size(WIDTH, HEIGHT)
+        .with_label(title);
+    let mut form = Form::new(10, 10, WIDTH - 20, HEIGHT - 60, None);
+    form.populate(gen);
+    let mut x = WIDTH - 90 * buttons.len() as i32;
+    for (i, label) in buttons.iter().enumerate() {
+        let answer = answer.clone();
+        if i == 0 {
+            let mut btn = button::ReturnButton::new(x, HEIGHT - 40, 80, 30, *label);
+            btn.set_callback(move |_| answer.set(Some(Answer::Accept)));
+        } else {
+            let mut btn = button::Button::new(x, HEIGHT - 40, 80, 30, *label);
+            btn.set_shortcut(enums::Shortcut::None | enums::Key::Escape);
+            btn.set_callback(move |_| answer.set(Some(Answer::Reject)));
+        }
+        x += 90;
+    }
+    win.end();
+    win.make_modal(true);
+    win.set_callback({
+        let answer = answer.clone();
+        move |_| answer.set(Some(Answer::Reject))
+    });
+    win.show();
+    (win, form, answer)
+}
+
+/// Shows `value` in a modal window with OK and Cancel buttons.
+/// Enter accepts and Escape cancels the dialog.
+/// Returns the edited value, or `None` if the dialog was cancelled.
+/// Invalid values are reported, scrolling to the first one, and the dialog stays open until
+/// they're fixed. The value is read back with `T::from_widget()`, so a `T` which can't be read
+/// back, like a struct with `&str` fields, can only be cancelled: use `view_dialog()` instead.
+pub fn edit_dialog<T: FltkForm>(value: &T, title: &str) -> Option<T> {
+    let (win, mut form, answer) = make_dialog(title, || value.generate(), &["OK", "Cancel"]);
+    let mut ret = None;
+    while win.shown() && app::wait() {
+        match answer.take() {
+            Some(Answer::Accept) => {
+                let invalid = match form.validate() {
+                    Err(FltkFormError::RejectedProps(invalid)) => invalid,
+                    _ => vec![],
+                };
+                if invalid.is_empty() {
+                    if let Some(v) = form.get_data::<T>() {
+                        ret = Some(v);
+                        break;
+                    }
+                    dialog::alert_default("The form couldn't be read back!");
+                } else {
+                    // nested fields are named `outer.inner`, scroll to the outer one
+                    let first = invalid[0].split('.').next().unwrap_or_default();
+                    form.scroll_to(first).ok();
+                    dialog::alert_default(&format!("Invalid values for: {}", invalid.join(", ")));
+                }
+            }
+            Some(Answer::Reject) => break,
+            None => (),
+        }
+    }
+    window::Window::delete(win);
+    ret
+}
+
+/// Shows `value` in a modal window with a Close button, until it's closed
+pub fn view_dialog<T: FltkForm>(value: &T, title: &str) {
+    let (win, _form, answer) = make_dialog(title, || value.view(), &["Close"]);
+    while win.shown() && app::wait() {
+        if answer.take().is_some() {
+            break;
+        }
+    }
+    window::Window::delete(win);
+}
@@ -0,0 +1,298 @@
+//! Array and `Vec<Vec<T>>` fields. An array is shown as a row of its items, and an array of
+//! arrays as a grid of fixed size. A `Vec<Vec<T>>` is a grid as well, which when generated for
+//! edition has buttons inserting and deleting rows and columns next to the focused cell.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, list, notify, register_accessor};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+
+#[derive(Clone, Copy)]
+pub(crate) struct GridOps {
+    /// The widget of a blank cell
+    pub(crate) make_cell: fn() -> Box<dyn WidgetExt>,
+}
+
+thread_local! {
+    static ARRAYS: RefCell<WidgetMap<()>> = RefCell::new(WidgetMap::new());
+    static GRIDS: RefCell<WidgetMap<Option<GridOps>>> = RefCell::new(WidgetMap::new());
+}
+
+fn is_array(wid: &widget::Widget) -> bool {
+    ARRAYS.with(|map| map.borrow_mut().get(wid).is_some())
+}
+
+fn ops_of(wid: &widget::Widget) -> Option<Option<GridOps>> {
+    GRIDS.with(|map| map.borrow_mut().get(wid))
+}
+
+pub(crate) fn make_array<F: FnMut(usize) -> Box<dyn WidgetExt>>(
+    len: usize,
+    make: F,
+) -> group::Flex {
+    let mut flex = group::Flex::default().row();
+    let items: Vec<widget::Widget> = (0..len)
+        .map(make)
+        .filter_map(|item| props::root_of(&*item))
+        .collect();
+    flex.end();
+    flex.set_pad(layout::MARGIN);
+    let h = if !items.is_empty() && items.iter().all(is_array) {
+        flex.set_type(group::FlexType::Column);
+        stack(&mut flex, &items)
+    } else {
+        row_height(&items)
+    };
+    flex.resize(flex.x(), flex.y(), flex.w(), h);
+    ARRAYS.with(|map| map.borrow_mut().insert(&flex, ()));
+    list::mark_list(&flex);
+    register_accessor(&flex, ValueAccessor::list());
+    flex
+}
+
+fn height_of(wid: &widget::Widget) -> i32 {
+    if wid.h() > 0 {
+        wid.h()
+    } else {
+        layout::ROW_HEIGHT
+    }
+}
+
+fn row_height(cells: &[widget::Widget]) -> i32 {
+    cells
+        .iter()
+        .map(height_of)
+        .max()
+        .unwrap_or(layout::ROW_HEIGHT)
+}
+
+fn stack(column: &mut group::Flex, rows: &[widget::Widget]) -> i32 {
+    let mut h = 0;
+    for row in rows {
+        column.fixed(row, height_of(row));
+        h += height_of(row) + layout::MARGIN;
+    }
+    (h - layout::MARGIN).max(layout::ROW_HEIGHT)
+}
+
+pub(crate) fn make_grid<F: FnMut(usize, usize) -> Box<dyn WidgetExt>>(
+    lens: &[usize],
+    mut make: F,
+    ops: Option<GridOps>,
+) -> group::Flex {
+    let mut grid = group::Flex::default().column();
+    grid.end();
+    grid.set_pad(layout::MARGIN);
+    GRIDS.with(|map| map.borrow_mut().insert(&grid, ops));
+    list::mark_list(&grid);
+    for (r, len) in lens.iter().enumerate() {
+        add_row(&mut grid, r as i32, *len, |c| make(r, c));
+    }
+    if let Some(ops) = ops {
+        grid.begin();
+        let mut bar = group::Flex::default().row();
+        let spacer = frame::Frame::default();
+        props::mark_container(&spacer);
+        let buttons = [
+            list::control("+ Row", "Insert a row below the focused cell"),
+            list::control("- Row", "Delete the row of the focused cell"),
+            list::control("+ Col", "Insert a column after the focused cell"),
+            list::control("- Col", "Delete the column of the focused cell"),
+        ];
+        bar.end();
+        grid.end();
+        props::mark_container(&bar);
+        bar.set_pad(layout::MARGIN);
+        for btn in &buttons {
+            bar.fixed(btn, 2 * layout::ROW_HEIGHT);
+        }
+        let [mut add_r, mut del_r, mut add_c, mut del_c] = buttons;
+        add_r.set_callback({
+            let mut grid = grid.clone();
+            move |_| {
+                let rows = rows_of(&grid);
+                let at = focused_cell(&grid).map_or(rows.len(), |(r, _)| r + 1);
+                let len = at
+                    .checked_sub(1)
+                    .and_then(|r| rows.get(r))
+                    .map_or(0, |row| row.children() as usize)
+                    .max(1);
+                add_row(&mut grid, at as i32, len, |_| (ops.make_cell)());
+                changed(&grid, at, 0);
+            }
+        });
+        del_r.set_callback({
+            let mut grid = grid.clone();
+            move |_| {
+                let rows = rows_of(&grid);
+                let at = focused_cell(&grid).map(|(r, _)| r);
+                if let Some(at) = at.or_else(|| rows.len().checked_sub(1)) {
+                    grid.remove(&rows[at]);
+                    group::Flex::delete(rows[at].clone());
+                    changed(&grid, at.min(rows.len().saturating_sub(2)), 0);
+                }
+            }
+        });
+        add_c.set_callback({
+            let grid = grid.clone();
+            move |_| {
+                let focused = focused_cell(&grid);
+                for mut row in rows_of(&grid) {
+                    let len = row.children() as usize;
+                    let at = focused.map_or(len, |(_, c)| (c + 1).min(len));
+                    add_cell(&mut row, at as i32, ops.make_cell);
+                }
+                let (r, c) = focused.map_or((0, 0), |(r, c)| (r, c + 1));
+                changed(&grid, r, c);
+            }
+        });
+        del_c.set_callback({
+            let grid = grid.clone();
+            move |_| {
+                let focused = focused_cell(&grid);
+                let rows = rows_of(&grid);
+                let at = match focused {
+                    Some((_, c)) => c,
+                    None => match rows.iter().map(|row| row.children()).max() {
+                        Some(cols) if cols > 0 => cols as usize - 1,
+                        _ => return,
+                    },
+                };
+                for mut row in rows {
+                    if let Some(cell) = row.child(at as i32) {
+                        row.remove(&cell);
+                        widget::Widget::delete(cell);
+                    }
+                }
+                let (r, c) = focused.map_or((0, 0), |(r, c)| (r, c.saturating_sub(1)));
+                changed(&grid, r, c);
+            }
+        });
+    }
+    if let Some(grid) = props::root_of(&grid) {
+        fit(&grid);
+    }
+    register_accessor(&grid, ValueAccessor::list());
+    grid
+}
+
+fn rows_of(grid: &group::Flex) -> Vec<group::Flex> {
+    (0..grid.children())
+        .filter_map(|i| grid.child(i))
+        .filter(|child| !props::is_container(child))
+        .filter_map(|child| group::Flex::from_dyn_widget_ptr(child.as_widget_ptr() as _))
+        .collect()
+}
+
+fn focused_cell(grid: &group::Flex) -> Option<(usize, usize)> {
+    let focus = app::focus()?;
+    rows_of(grid).iter().enumerate().find_map(|(r, row)| {
+        (0..row.children())
+            .filter_map(|c| row.child(c))
+            .position(|cell| focus.inside(&cell))
+            .map(|c| (r, c))
+    })
+}
+
+fn add_row<F: FnMut(usize) -> Box<dyn WidgetExt>>(
+    grid: &mut group::Flex,
+    index: i32,
+    len: usize,
+    make: F,
+) {
+    let (mut row, cells) = layout::with_current(grid, || {
+        let row = group::Flex::default().row();
+        let cells: Vec<widget::Widget> = (0..len)
+            .map(make)
+            .filter_map(|cell| props::root_of(&*cell))
+            .collect();
+        row.end();
+        (row, cells)
+    });
+    grid.insert(&row, index);
+    row.set_pad(layout::MARGIN);
+    for mut cell in cells {
+        layout::apply_parent_style(&mut cell);
+    }
+    list::mark_list(&row);
+    register_accessor(&row, ValueAccessor::list());
+}
+
+fn add_cell(row: &mut group::Flex, index: i32, make: fn() -> Box<dyn WidgetExt>) {
+    let cell = layout::with_current(row, make);
+    if let Some(mut cell) = props::root_of(&*cell) {
+        row.insert(&cell, index);
+        layout::apply_parent_style(&mut cell);
+    }
+}
+
+pub(crate) fn fit(wid: &widget::Widget) {
+    let editable = match ops_of(wid) {
+        Some(ops) => ops.is_some(),
+        None => return,
+    };
+    let mut grid = match group::Flex::from_dyn_widget_ptr(wid.as_widget_ptr() as _) {
+        Some(grid) => grid,
+        None => return,
+    };
+    let mut rows: Vec<widget::Widget> = rows_of(&grid)
+        .iter()
+        .filter_map(|row| props::root_of(row))
+        .collect();
+    for row in &mut rows {
+        let cells: Vec<widget::Widget> = row
+            .as_group()
+            .map(|row| (0..row.children()).filter_map(|c| row.child(c)).collect())
+            .unwrap_or_default();
+        row.set_size(row.w(), row_height(&cells));
+    }
+    let mut h = if rows.is_empty() {
+        0
+    } else {
+        stack(&mut grid, &rows)
+    };
+    if editable {
+        if let Some(bar) = grid.child(grid.children() - 1) {
+            grid.fixed(&bar, layout::ROW_HEIGHT);
+        }
+        h += layout::ROW_HEIGHT + if rows.is_empty() { 0 } else { layout::MARGIN };
+    }
+    grid.resize(grid.x(), grid.y(), grid.w(), h.max(layout::ROW_HEIGHT));
+    layout::set_preferred_height(wid, grid.h());
+}
+
+fn changed(grid: &group::Flex, row: usize, col: usize) {
+    let wid = match props::root_of(grid) {
+        Some(wid) => wid,
+        None => return,
+    };
+    if notify::is_notifying(&wid) {
+        notify::watch(&wid);
+    }
+    fit(&wid);
+    layout::relayout(&wid);
+    let rows = rows_of(grid);
+    if let Some(row) = rows.get(row).or_else(|| rows.last()) {
+        let last = (row.children() - 1).max(0);
+        if let Some(cell) = row.child((col as i32).min(last)) {
+            cell.clone().take_focus().ok();
+        }
+    }
+    wid.clone().redraw();
+    notify::notify(&wid);
+}
+
+pub(crate) fn set_editable(wid: &widget::Widget, editable: bool) {
+    if let Some(Some(_)) = ops_of(wid) {
+        if let Some(mut bar) = wid
+            .as_group()
+            .and_then(|grid| grid.child(grid.children() - 1))
+        {
+            if editable {
+                bar.activate();
+            } else {
+                bar.deactivate();
+            }
+        }
+    }
+}
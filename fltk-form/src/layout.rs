@@ -0,0 +1,859 @@
+use crate::props::{self, WidgetMap};
+use crate::{grid, list, map, notify, table, FormStyle};
+use fltk::{prelude::*, utils::is_ptr_of, *};
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+
+/// The height of a single line field
+pub const ROW_HEIGHT: i32 = 30;
+/// The vertical space between fields
+pub const SPACING: i32 = 5;
+/// The space around the fields and between the labels and the fields
+pub const MARGIN: i32 = 5;
+
+/// How a generated struct places its fields and their labels
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum FormLayout {
+    /// One field per row, labels on the left of their field
+    #[default]
+    LabelsInline,
+    /// One field per row, labels above their field
+    LabelsOnTop,
+    /// N fields per row, labels above their field
+    Grid(usize),
+}
+
+thread_local! {
+    static CURRENT_LAYOUT: Cell<FormLayout> = Cell::new(FormLayout::default());
+    static CURRENT_STYLE: Cell<FormStyle> = Cell::new(FormStyle::default());
+    static STYLES: RefCell<WidgetMap<FormStyle>> = RefCell::new(WidgetMap::new());
+    static LAYOUTS: RefCell<WidgetMap<FormLayout>> = RefCell::new(WidgetMap::new());
+    static HEIGHTS: RefCell<WidgetMap<i32>> = RefCell::new(WidgetMap::new());
+    static SECTIONS: RefCell<WidgetMap<Section>> = RefCell::new(WidgetMap::new());
+    static ROWS: RefCell<WidgetMap<()>> = RefCell::new(WidgetMap::new());
+    static FIELDS: RefCell<WidgetMap<FieldState>> = RefCell::new(WidgetMap::new());
+    static WIZARD: Cell<bool> = const { Cell::new(false) };
+}
+
+#[derive(Clone, Default)]
+struct Section {
+    collapsed: bool,
+    /// The fields hidden when the section was collapsed, shown again when it's expanded
+    hidden: Vec<widget::Widget>,
+}
+
+pub(crate) fn with_wizard<R, F: FnOnce() -> R>(f: F) -> R {
+    let prev = WIZARD.with(|wizard| wizard.replace(true));
+    let ret = f();
+    WIZARD.with(|wizard| wizard.set(prev));
+    ret
+}
+
+pub(crate) fn with_layout<R, F: FnOnce() -> R>(layout: FormLayout, f: F) -> R {
+    let prev = CURRENT_LAYOUT.with(|current| current.replace(layout));
+    let ret = f();
+    CURRENT_LAYOUT.with(|current| current.set(prev));
+    ret
+}
+
+pub(crate) fn with_style<R, F: FnOnce() -> R>(style: FormStyle, f: F) -> R {
+    let prev = CURRENT_STYLE.with(|current| current.replace(style));
+    let ret = f();
+    CURRENT_STYLE.with(|current| current.set(prev));
+    ret
+}
+
+/// Runs `f` with `grp` as the current group, then makes the previous one current again, since
+/// widgets are also added from callbacks, which shouldn't change the current group
+pub(crate) fn with_current<G: GroupExt, R, F: FnOnce() -> R>(grp: &G, f: F) -> R {
+    let prev = group::Group::try_current();
+    grp.begin();
+    let ret = f();
+    group::Group::set_current(prev.as_ref());
+    ret
+}
+
+fn style_of(w: &dyn WidgetExt) -> FormStyle {
+    STYLES
+        .with(|map| map.borrow_mut().get(w))
+        .unwrap_or_default()
+}
+
+fn preferred_height(w: &widget::Widget) -> i32 {
+    HEIGHTS
+        .with(|map| map.borrow_mut().get(w))
+        .unwrap_or(ROW_HEIGHT)
+}
+
+pub(crate) fn set_preferred_height(w: &widget::Widget, h: i32) {
+    HEIGHTS.with(|map| map.borrow_mut().insert(w, h));
+}
+
+/// Lays out the fields of a generated struct according to a `FormLayout`,
+/// by default in two columns, the labels on the left and the fields taking the remaining width.
+/// Used by the derived `FltkForm` implementations.
+///
+/// Fields are added while the builder is the current group, so the widget of a field should be
+/// generated just before it's added:
+/// ```rust,no_run
+/// use fltk_form::{FieldsBuilder, FltkForm};
+///
+/// let mut f = FieldsBuilder::new("MyStruct");
+/// f.add("a", 1.0f64.generate());
+/// f.add("b", String::from("text").generate());
+/// let form = f.finish();
+/// ```
+pub struct FieldsBuilder {
+    flex: group::Flex,
+    layout: FormLayout,
+    style: FormStyle,
+    wizard: bool,
+    /// The `group::Tabs` or `group::Wizard` holding the pages
+    stack: Option<group::Group>,
+    pages: Vec<group::Flex>,
+    /// The widgets of the fields, in the order they were added
+    fields: Vec<widget::Widget>,
+    conditions: Vec<Condition>,
+}
+
+struct Condition {
+    field: usize,
+    visibility: bool,
+    deps: Vec<usize>,
+    predicate: fn(&[widget::Widget]) -> Option<bool>,
+}
+
+impl FieldsBuilder {
+    pub fn new(name: &str) -> Self {
+        let flex = group::Flex::default()
+            .column()
+            .with_label(name)
+            .with_align(enums::Align::Left | enums::Align::Top);
+        Self {
+            flex,
+            layout: CURRENT_LAYOUT.with(|current| current.get()),
+            style: CURRENT_STYLE.with(|current| current.get()),
+            wizard: WIZARD.with(|wizard| wizard.replace(false)),
+            stack: None,
+            pages: vec![],
+            fields: vec![],
+            conditions: vec![],
+        }
+    }
+
+    /// Uses `layout` instead of the one of the `Form` being populated
+    pub fn with_layout(mut self, layout: FormLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Labels the widget of a field, keeping the height it was generated with, if any.
+    /// A nested struct generated by a `FieldsBuilder` becomes a titled, collapsible section.
+    pub fn add(&mut self, name: &str, mut w: Box<dyn WidgetExt>) {
+        w.set_label(name);
+        if let Some(mut field) = props::root_of(&*w) {
+            apply_style(&mut field, &self.style);
+        }
+        if let Some(layout) = LAYOUTS.with(|map| map.borrow_mut().get(&*w)) {
+            if let Some(mut flex) = group::Flex::from_dyn_widget_ptr(w.as_widget_ptr() as _) {
+                make_section(&mut flex);
+                arrange(&mut flex, layout);
+            }
+        }
+        let h = if w.h() > 0 {
+            w.h()
+        } else {
+            self.style.row_height
+        };
+        HEIGHTS.with(|map| map.borrow_mut().insert(&*w, h));
+        self.fields.extend(props::root_of(&*w));
+    }
+
+    /// Shows the field added last only while `predicate` holds. The predicate is given the
+    /// widgets of the fields in the order they were added, and is evaluated again each time
+    /// one of the fields at the positions `deps` changes. `None` leaves the field as it is.
+    /// ```rust,no_run
+    /// use fltk_form::{FieldsBuilder, FltkForm};
+    ///
+    /// let mut f = FieldsBuilder::new("Proxy");
+    /// f.add("use_proxy", false.generate());
+    /// f.add("host", String::new().generate());
+    /// f.visible_if(&[0], |fields| bool::from_widget(fields.first()?));
+    /// let form = f.finish();
+    /// ```
+    pub fn visible_if(&mut self, deps: &[usize], predicate: fn(&[widget::Widget]) -> Option<bool>) {
+        self.add_condition(true, deps, predicate);
+    }
+
+    /// Enables the field added last only while `predicate` holds, like `visible_if()`
+    pub fn enabled_if(&mut self, deps: &[usize], predicate: fn(&[widget::Widget]) -> Option<bool>) {
+        self.add_condition(false, deps, predicate);
+    }
+
+    fn add_condition(
+        &mut self,
+        visibility: bool,
+        deps: &[usize],
+        predicate: fn(&[widget::Widget]) -> Option<bool>,
+    ) {
+        if let Some(field) = self.fields.len().checked_sub(1) {
+            self.conditions.push(Condition {
+                field,
+                visibility,
+                deps: deps.to_vec(),
+                predicate,
+            });
+        }
+    }
+
+    /// Adds the next fields to the page `name` of a `group::Tabs` placed after the other fields,
+    /// creating the tabs and the page if needed. Ignored when the struct is shown by a `Wizard`.
+    pub fn tab(&mut self, name: &str) {
+        if !self.wizard {
+            self.begin_page(name, || group::Tabs::default().as_group());
+        }
+    }
+
+    /// Adds the next fields to the page `n` when the struct is shown by a `Wizard`,
+    /// pages being shown in the order they're started. Ignored otherwise.
+    pub fn page(&mut self, n: usize) {
+        if self.wizard {
+            self.begin_page(&n.to_string(), || group::Wizard::default().as_group());
+            // unlike tabs, the pages of a wizard have no visible title
+            if let Some(page) = self.pages.last_mut() {
+                page.set_label_type(enums::LabelType::None);
+            }
+        }
+    }
+
+    fn begin_page(&mut self, name: &str, make_stack: fn() -> Option<group::Group>) {
+        if let Some(page) = self.pages.iter().find(|page| page.label() == name) {
+            page.begin();
+            return;
+        }
+        if self.stack.is_none() {
+            self.flex.begin();
+            self.stack = make_stack();
+            if let Some(stack) = self.stack.as_mut() {
+                stack.end();
+                stack.set_color(self.style.background);
+                stack.set_selection_color(self.style.background);
+                props::mark_container(&*stack);
+            }
+        }
+        if let Some(stack) = self.stack.as_mut() {
+            stack.begin();
+            let mut page = group::Flex::default().column().with_label(name);
+            page.set_label_font(self.style.label_font);
+            page.set_label_size(self.style.label_size);
+            page.set_label_color(self.style.label_color);
+            props::mark_container(&page);
+            if self.pages.is_empty() {
+                stack.resizable(&page);
+            }
+            self.pages.push(page);
+        }
+    }
+
+    /// Ends the group and sizes it to fit its content, using the width of its parent if any
+    pub fn finish(self) -> Box<dyn WidgetExt> {
+        let mut flex = self.flex;
+        flex.end();
+        let fields = Rc::new(self.fields);
+        for cond in self.conditions {
+            let mut target = fields[cond.field].clone();
+            // hidden before arranging, so they take no row
+            match (cond.predicate)(&fields) {
+                Some(false) if cond.visibility => target.hide(),
+                Some(enabled) if !cond.visibility => set_enabled(&target, enabled),
+                _ => (),
+            }
+            for dep in cond.deps.iter().filter_map(|&dep| fields.get(dep)) {
+                let fields = fields.clone();
+                let target = target.clone();
+                let predicate = cond.predicate;
+                let visibility = cond.visibility;
+                notify::on_change(dep, move |_| match predicate(&fields) {
+                    Some(visible) if visibility => set_visible(&target, visible),
+                    Some(enabled) => set_enabled(&target, enabled),
+                    None => (),
+                });
+            }
+        }
+        LAYOUTS.with(|map| map.borrow_mut().insert(&flex, self.layout));
+        STYLES.with(|map| map.borrow_mut().insert(&flex, self.style));
+        props::register_accessor(&flex, props::ValueAccessor::fields());
+        if let Some(parent) = flex.parent() {
+            flex.resize(parent.x(), parent.y(), parent.w(), flex.h());
+        }
+        if let Some(mut stack) = self.stack {
+            stack.resize(flex.x(), flex.y(), flex.w(), stack.h());
+            for mut page in self.pages {
+                LAYOUTS.with(|map| map.borrow_mut().insert(&page, self.layout));
+                STYLES.with(|map| map.borrow_mut().insert(&page, self.style));
+                arrange(&mut page, self.layout);
+            }
+            fit_stack(&mut stack);
+        }
+        arrange(&mut flex, self.layout);
+        Box::new(flex)
+    }
+}
+
+fn make_section(flex: &mut group::Flex) {
+    SECTIONS.with(|map| map.borrow_mut().insert(&*flex, Section::default()));
+    flex.set_frame(enums::FrameType::EngravedFrame);
+    // the title is drawn in the header, after an arrow showing whether the section is collapsed
+    flex.set_label_type(enums::LabelType::None);
+    flex.draw(|f| {
+        let header = style_of(&*f).row_height;
+        let x = f.x() + 2 * MARGIN;
+        let y = f.y() + header / 2;
+        draw::set_draw_color(f.label_color());
+        if is_collapsed(&*f) {
+            draw::draw_polygon(x + 2, y - 5, x + 7, y, x + 2, y + 5);
+        } else {
+            draw::draw_polygon(x, y - 3, x + 10, y - 3, x + 5, y + 3);
+        }
+        draw::set_font(f.label_font(), f.label_size());
+        let text_x = x + 10 + MARGIN;
+        draw::draw_text2(
+            &f.label(),
+            text_x,
+            f.y(),
+            f.x() + f.w() - text_x - MARGIN,
+            header,
+            enums::Align::Left,
+        );
+    });
+    flex.handle(|f, ev| {
+        if ev == enums::Event::Push && app::event_y() < f.y() + style_of(&*f).row_height {
+            if let Some(section) = props::root_of(&*f) {
+                set_collapsed(&section, !is_collapsed(&section));
+            }
+            true
+        } else {
+            false
+        }
+    });
+}
+
+fn section_of(wid: &dyn WidgetExt) -> Option<Section> {
+    SECTIONS.with(|map| map.borrow_mut().get(wid))
+}
+
+pub(crate) fn is_section(wid: &widget::Widget) -> bool {
+    section_of(wid).is_some()
+}
+
+pub(crate) fn is_collapsed(wid: &dyn WidgetExt) -> bool {
+    section_of(wid)
+        .map(|section| section.collapsed)
+        .unwrap_or(false)
+}
+
+pub(crate) fn set_collapsed(wid: &widget::Widget, collapsed: bool) {
+    let section = match section_of(wid) {
+        Some(section) if section.collapsed != collapsed => section,
+        _ => return,
+    };
+    let hidden = if collapsed {
+        let shown: Vec<widget::Widget> = own_fields(wid)
+            .into_iter()
+            .filter(|field| field.visible())
+            .collect();
+        for field in &shown {
+            field.clone().hide();
+        }
+        shown
+    } else {
+        for field in &section.hidden {
+            field.clone().show();
+        }
+        vec![]
+    };
+    SECTIONS.with(|map| map.borrow_mut().insert(wid, Section { collapsed, hidden }));
+    if let Some(layout) = LAYOUTS.with(|map| map.borrow_mut().get(wid)) {
+        if let Some(mut flex) = group::Flex::from_dyn_widget_ptr(wid.as_widget_ptr() as _) {
+            arrange(&mut flex, layout);
+            flex.redraw();
+        }
+    }
+    relayout(wid);
+}
+
+pub(crate) fn sections_of(root: &widget::Widget) -> Vec<(String, widget::Widget)> {
+    fn walk(root: &widget::Widget, prefix: &str, out: &mut Vec<(String, widget::Widget)>) {
+        for field in props::props_of(root) {
+            if is_section(&field) {
+                let path = if prefix.is_empty() {
+                    field.label()
+                } else {
+                    format!("{}.{}", prefix, field.label())
+                };
+                out.push((path.clone(), field.clone()));
+                walk(&field, &path, out);
+            }
+        }
+    }
+    let mut out = vec![];
+    walk(root, "", &mut out);
+    out
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+struct FieldState {
+    disabled: bool,
+    readonly: bool,
+}
+
+fn field_state(wid: &dyn WidgetExt) -> FieldState {
+    FIELDS
+        .with(|map| map.borrow_mut().get(wid))
+        .unwrap_or_default()
+}
+
+fn is_input(wid: &widget::Widget) -> bool {
+    input::Input::from_dyn_widget_ptr(wid.as_widget_ptr() as _).is_some()
+}
+
+fn update_activation(wid: &widget::Widget) {
+    let state = field_state(wid);
+    let mut w = wid.clone();
+    if state.disabled || (state.readonly && !is_input(wid)) {
+        w.deactivate();
+    } else {
+        w.activate();
+    }
+    w.redraw();
+}
+
+pub(crate) fn set_enabled(wid: &widget::Widget, enabled: bool) {
+    let state = FieldState {
+        disabled: !enabled,
+        ..field_state(wid)
+    };
+    FIELDS.with(|map| map.borrow_mut().insert(wid, state));
+    update_activation(wid);
+}
+
+pub(crate) fn set_readonly(wid: &widget::Widget, readonly: bool) {
+    if wid.as_group().is_some() {
+        for field in props::props_of(wid) {
+            set_readonly(&field, readonly);
+        }
+        list::set_editable(wid, !readonly);
+        grid::set_editable(wid, !readonly);
+        table::set_editable(wid, !readonly);
+        return;
+    }
+    let state = FieldState {
+        readonly,
+        ..field_state(wid)
+    };
+    FIELDS.with(|map| map.borrow_mut().insert(wid, state));
+    let ptr = wid.as_widget_ptr();
+    // outputs are always read-only
+    if !is_ptr_of::<output::Output>(ptr) {
+        if let Some(mut input) = input::Input::from_dyn_widget_ptr(ptr as _) {
+            input.set_readonly(readonly);
+        }
+    }
+    update_activation(wid);
+}
+
+pub(crate) fn set_visible(wid: &widget::Widget, visible: bool) {
+    let mut owner = wid.parent();
+    if let Some(row) = owner
+        .as_ref()
+        .and_then(|o| props::root_of(o))
+        .filter(is_row)
+    {
+        owner = row.parent();
+    }
+    if let Some(owner) = owner {
+        if let Some(mut section) = section_of(&owner).filter(|section| section.collapsed) {
+            section
+                .hidden
+                .retain(|field| field.as_widget_ptr() != wid.as_widget_ptr());
+            if visible {
+                section.hidden.push(wid.clone());
+            }
+            SECTIONS.with(|map| map.borrow_mut().insert(&owner, section));
+            return;
+        }
+    }
+    if wid.visible() == visible {
+        return;
+    }
+    let mut w = wid.clone();
+    if visible {
+        w.show();
+    } else {
+        w.hide();
+    }
+    relayout(wid);
+}
+
+pub(crate) fn fit_scroll(root: &widget::Widget) {
+    let parent = match root.parent() {
+        Some(parent) if is_ptr_of::<group::Scroll>(parent.as_widget_ptr()) => parent,
+        _ => return,
+    };
+    if let Some(mut scroll) = group::Scroll::from_dyn_widget_ptr(parent.as_widget_ptr() as _) {
+        let w = if root.h() > scroll.h() {
+            let size = scroll.scrollbar_size();
+            scroll.w()
+                - if size > 0 {
+                    size
+                } else {
+                    app::scrollbar_size()
+                }
+        } else {
+            scroll.w()
+        };
+        if root.w() != w {
+            root.clone().resize(scroll.x(), root.y(), w, root.h());
+        }
+        scroll.redraw();
+    }
+}
+
+fn fit_stack(stack: &mut group::Group) {
+    let mut pages = vec![];
+    for i in 0..stack.children() {
+        if let Some(page) = stack.child(i) {
+            pages.push(page);
+        }
+    }
+    let bar = if is_ptr_of::<group::Tabs>(stack.as_widget_ptr()) {
+        pages
+            .first()
+            .map(|page| style_of(page).row_height)
+            .unwrap_or(ROW_HEIGHT)
+    } else {
+        0
+    };
+    let h = pages.iter().map(preferred_height).max().unwrap_or(0);
+    stack.resize(stack.x(), stack.y(), stack.w(), bar + h);
+    for page in &mut pages {
+        page.resize(stack.x(), stack.y() + bar, stack.w(), h);
+    }
+    stack.init_sizes();
+    HEIGHTS.with(|map| map.borrow_mut().insert(&*stack, stack.h()));
+}
+
+fn as_stack(wid: &widget::Widget) -> Option<group::Group> {
+    let ptr = wid.as_widget_ptr();
+    if is_ptr_of::<group::Tabs>(ptr) || is_ptr_of::<group::Wizard>(ptr) {
+        wid.as_group()
+    } else {
+        None
+    }
+}
+
+pub(crate) fn wizard_of(root: &widget::Widget) -> Option<group::Wizard> {
+    own_fields(root)
+        .iter()
+        .find(|field| is_ptr_of::<group::Wizard>(field.as_widget_ptr()))
+        .and_then(|field| group::Wizard::from_dyn_widget_ptr(field.as_widget_ptr() as _))
+}
+
+fn is_row(wid: &widget::Widget) -> bool {
+    ROWS.with(|map| map.borrow_mut().get(wid).is_some())
+}
+
+pub(crate) fn own_fields(root: &widget::Widget) -> Vec<widget::Widget> {
+    let mut v = vec![];
+    if let Some(grp) = root.as_group() {
+        for i in 0..grp.children() {
+            if let Some(child) = grp.child(i) {
+                if is_row(&child) {
+                    v.append(&mut own_fields(&child));
+                } else {
+                    v.push(child);
+                }
+            }
+        }
+    }
+    v
+}
+
+fn mark_row(wid: &dyn WidgetExt) {
+    props::mark_container(wid);
+    ROWS.with(|map| map.borrow_mut().insert(wid, ()));
+}
+
+fn apply_style(w: &mut widget::Widget, style: &FormStyle) {
+    w.set_label_font(style.label_font);
+    w.set_label_size(style.label_size);
+    w.set_label_color(style.label_color);
+    let ptr = w.as_widget_ptr();
+    if let Some(mut input) = input::Input::from_dyn_widget_ptr(ptr as _) {
+        input.set_color(style.input_color);
+        input.set_text_color(style.text_color);
+    } else if let Some(mut choice) = menu::Choice::from_dyn_widget_ptr(ptr as _) {
+        choice.set_color(style.background);
+        choice.set_text_color(style.text_color);
+    } else if let Some(grp) = w.as_group() {
+        // nested structs and pages are styled by their own builder
+        if LAYOUTS.with(|map| map.borrow_mut().get(&*w)).is_none() && as_stack(w).is_none() {
+            for i in 0..grp.children() {
+                if let Some(mut child) = grp.child(i) {
+                    apply_style(&mut child, style);
+                }
+            }
+        }
+    }
+}
+
+pub(crate) fn apply_parent_style(w: &mut widget::Widget) {
+    let style = parent_style(w);
+    apply_style(w, &style);
+}
+
+pub(crate) fn parent_style(w: &widget::Widget) -> FormStyle {
+    let mut parent = w.parent();
+    loop {
+        match parent {
+            Some(grp) => match STYLES.with(|map| map.borrow_mut().get(&grp)) {
+                Some(style) => return style,
+                None => parent = grp.parent(),
+            },
+            None => return CURRENT_STYLE.with(|current| current.get()),
+        }
+    }
+}
+
+pub(crate) fn set_style(root: &widget::Widget, style: &FormStyle) {
+    for mut field in own_fields(root) {
+        apply_style(&mut field, style);
+        if let Some(mut stack) = as_stack(&field) {
+            stack.set_color(style.background);
+            stack.set_selection_color(style.background);
+            for i in 0..stack.children() {
+                if let Some(mut page) = stack.child(i) {
+                    apply_style(&mut page, style);
+                    set_style(&page, style);
+                }
+            }
+            fit_stack(&mut stack);
+        } else if LAYOUTS.with(|map| map.borrow_mut().get(&field)).is_some() {
+            set_style(&field, style);
+        }
+    }
+    if let Some(layout) = LAYOUTS.with(|map| map.borrow_mut().get(root)) {
+        STYLES.with(|map| map.borrow_mut().insert(root, *style));
+        if let Some(mut flex) = group::Flex::from_dyn_widget_ptr(root.as_widget_ptr() as _) {
+            arrange(&mut flex, layout);
+            flex.redraw();
+        }
+    }
+}
+
+pub(crate) fn mark_invalid(root: &widget::Widget, style: &FormStyle) -> Vec<String> {
+    let mut invalid = vec![];
+    for field in props::props_of(root) {
+        if is_section(&field) {
+            for name in mark_invalid(&field, style) {
+                invalid.push(format!("{}.{}", field.label(), name));
+            }
+            continue;
+        }
+        let valid = props::is_valid_field(&field);
+        if !valid {
+            invalid.push(field.label());
+        }
+        if field.as_group().is_some() {
+            // the items of a list
+            for item in props::props_of(&field) {
+                let valid = props::is_valid_field(&item);
+                set_error_color(&item, style, !valid);
+            }
+            map::mark_keys(&field, style);
+        } else {
+            set_error_color(&field, style, !valid);
+        }
+    }
+    invalid
+}
+
+pub(crate) fn set_error_color(w: &widget::Widget, style: &FormStyle, error: bool) {
+    let ptr = w.as_widget_ptr();
+    if let Some(mut input) = input::Input::from_dyn_widget_ptr(ptr as _) {
+        input.set_color(if error {
+            style.error_color
+        } else {
+            style.input_color
+        });
+        input.redraw();
+    } else if let Some(mut choice) = menu::Choice::from_dyn_widget_ptr(ptr as _) {
+        choice.set_color(if error {
+            style.error_color
+        } else {
+            style.background
+        });
+        choice.redraw();
+    }
+}
+
+fn label_size(w: &widget::Widget) -> (i32, i32) {
+    draw::set_font(w.label_font(), w.label_size());
+    draw::measure(&w.label(), false)
+}
+
+pub(crate) fn arrange(flex: &mut group::Flex, layout: FormLayout) {
+    let fields = props::root_of(flex)
+        .map(|root| own_fields(&root))
+        .unwrap_or_default();
+    for field in &fields {
+        flex.add(field);
+    }
+    let mut i = 0;
+    while i < flex.children() {
+        match flex.child(i) {
+            Some(child) if is_row(&child) => {
+                flex.remove(&child);
+                widget::Widget::delete(child);
+            }
+            _ => i += 1,
+        }
+    }
+    let (label_w, label_h) = fields
+        .iter()
+        .filter(|f| f.visible() && !is_section(f) && as_stack(f).is_none())
+        .map(label_size)
+        .fold((0, 0), |(w, h), (fw, fh)| (w.max(fw), h.max(fh)));
+    let style = style_of(&*flex);
+    // the title of a section takes a row above its fields
+    let header = if section_of(&*flex).is_some() {
+        style.row_height - MARGIN
+    } else {
+        0
+    };
+    let (align, top, pad) = if layout == FormLayout::LabelsInline {
+        flex.set_margins(label_w + 2 * MARGIN, MARGIN + header, MARGIN, MARGIN);
+        (enums::Align::Left, MARGIN + header, style.spacing)
+    } else {
+        flex.set_margins(MARGIN, MARGIN + header + label_h, MARGIN, MARGIN);
+        (
+            enums::Align::Left | enums::Align::Top,
+            MARGIN + header + label_h,
+            style.spacing + label_h,
+        )
+    };
+    flex.set_pad(pad);
+    let mut heights = vec![];
+    match layout {
+        FormLayout::Grid(columns) => {
+            let columns = columns.max(1);
+            let mut rows: Vec<group::Flex> = vec![];
+            let mut count = 0;
+            for field in &fields {
+                let mut field = field.clone();
+                field.set_align(align);
+                let visible = field.visible();
+                if visible && count % columns == 0 {
+                    let mut row = group::Flex::default().row();
+                    row.end();
+                    row.set_pad(2 * MARGIN);
+                    flex.add(&row);
+                    mark_row(&row);
+                    rows.push(row);
+                    heights.push(0);
+                }
+                if let Some(row) = rows.last_mut() {
+                    row.add(&field);
+                }
+                if visible {
+                    count += 1;
+                    if let Some(h) = heights.last_mut() {
+                        *h = (*h).max(preferred_height(&field));
+                    }
+                }
+            }
+            if let Some(row) = rows.last_mut() {
+                while count % columns != 0 {
+                    let filler = frame::Frame::default();
+                    row.add(&filler);
+                    mark_row(&filler);
+                    count += 1;
+                }
+            }
+            for (row, h) in rows.iter().zip(heights.iter()) {
+                flex.fixed(row, *h);
+            }
+        }
+        _ => {
+            for field in &fields {
+                let mut field = field.clone();
+                field.set_align(align);
+                if field.visible() {
+                    let h = preferred_height(&field);
+                    flex.fixed(&field, h);
+                    heights.push(h);
+                }
+            }
+        }
+    }
+    let content: i32 = heights.iter().sum();
+    let gaps = (heights.len() as i32 - 1).max(0) * pad;
+    flex.resize(flex.x(), flex.y(), flex.w(), top + content + gaps + MARGIN);
+    HEIGHTS.with(|map| map.borrow_mut().insert(&*flex, flex.h()));
+}
+
+/// Removes a widget and deletes it once the running callback returns, since the callback may
+/// belong to the widget or one of its children
+pub(crate) fn delete_later<W: WidgetBase + Clone + 'static>(mut wid: W) {
+    wid.hide();
+    if let Some(mut parent) = wid.parent() {
+        parent.remove(&wid);
+    }
+    app::add_timeout3(0.0, move |_| {
+        if !wid.was_deleted() {
+            W::delete(wid.clone());
+        }
+    });
+}
+
+pub(crate) fn relayout(wid: &widget::Widget) {
+    let mut parent = wid.parent();
+    while let Some(grp) = parent.as_ref().and_then(|grp| props::root_of(grp)) {
+        if let Some(mut stack) = as_stack(&grp) {
+            fit_stack(&mut stack);
+        }
+        if list::is_list(&grp) {
+            list::fit(&grp);
+            grid::fit(&grp);
+        }
+        if let Some(layout) = LAYOUTS.with(|map| map.borrow_mut().get(&grp)) {
+            if let Some(mut flex) = group::Flex::from_dyn_widget_ptr(grp.as_widget_ptr() as _) {
+                arrange(&mut flex, layout);
+                flex.redraw();
+            }
+        }
+        fit_scroll(&grp);
+        parent = grp.parent();
+    }
+}
+
+pub(crate) fn set_layout(root: &widget::Widget, layout: FormLayout) {
+    for mut stack in own_fields(root).iter().filter_map(as_stack) {
+        for i in 0..stack.children() {
+            if let Some(page) = stack.child(i) {
+                if let Some(mut page) = group::Flex::from_dyn_widget_ptr(page.as_widget_ptr() as _)
+                {
+                    LAYOUTS.with(|map| map.borrow_mut().insert(&page, layout));
+                    arrange(&mut page, layout);
+                }
+            }
+        }
+        fit_stack(&mut stack);
+    }
+    if let Some(mut flex) = group::Flex::from_dyn_widget_ptr(root.as_widget_ptr() as _) {
+        LAYOUTS.with(|map| map.borrow_mut().insert(&flex, layout));
+        arrange(&mut flex, layout);
+        flex.redraw();
+    }
+    fit_scroll(root);
+}
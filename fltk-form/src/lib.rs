@@ -163,11 +163,43 @@
     ```
 */
 
-use fltk::{image::*, prelude::*, utils::is_ptr_of, *};
-use std::collections::HashMap;
+use fltk::{image::*, prelude::*, *};
+use std::cell::RefCell;
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
 use std::fmt;
+use std::hash::Hash;
 use std::path::Path;
-
+use std::rc::Rc;
+use std::str::FromStr;
+
+mod dialog;
+mod grid;
+mod layout;
+mod list;
+mod map;
+mod notify;
+mod option;
+mod path;
+mod props;
+mod secret;
+mod set;
+mod style;
+mod table;
+mod text;
+mod tuple;
+mod wizard;
+pub use dialog::{edit_dialog, view_dialog};
+pub use layout::{FieldsBuilder, FormLayout};
+pub use notify::on_change;
+pub use path::{FlPath, PathOptions};
+pub use props::{fields_of, register_accessor, value_of, ValueAccessor};
+pub use secret::FlSecret;
+#[cfg(feature = "bitflags")]
+pub use set::FlFlags;
+pub use style::FormStyle;
+pub use table::FlTable;
+pub use text::FlText;
+pub use wizard::Wizard;
 pub mod utils;
 
 pub fn make_image_frame<P: AsRef<Path>>(filename: P) -> frame::Frame {
@@ -183,6 +215,23 @@ pub fn make_image_frame<P: AsRef<Path>>(filename: P) -> frame::Frame {
     frame
 }
 
+fn image_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| wid.tooltip(),
+        check: |_, value| Path::new(value).exists(),
+        set: |wid, value| {
+            let mut frame = wid.clone();
+            let img = SharedImage::load(value).ok();
+            if let Some(ref img) = img {
+                frame.set_size(img.width(), img.height());
+            }
+            frame.set_image(img);
+            frame.set_tooltip(value);
+            frame.redraw();
+        },
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FlImage(pub String);
 
@@ -191,12 +240,86 @@ impl fmt::Display for FlImage {
         write!(f, "{}", self.0)
     }
 }
+
+fn set_swatch<W: WidgetExt>(swatch: &mut W, color: enums::Color) {
+    swatch.set_color(color);
+    swatch.set_selection_color(color);
+    swatch.set_tooltip(&color.to_hex_str());
+    swatch.redraw();
+}
+
+/// A swatch opening fltk's color chooser when clicked
+pub fn make_color_button(color: enums::Color) -> button::Button {
+    let mut btn = button::Button::default();
+    btn.set_frame(enums::FrameType::BorderBox);
+    btn.set_down_frame(enums::FrameType::BorderBox);
+    set_swatch(&mut btn, color);
+    btn.set_callback(|btn| {
+        let (r, g, b) = fltk::dialog::color_chooser_with_default(
+            &btn.label(),
+            fltk::dialog::ColorMode::Byte,
+            btn.color().to_rgb(),
+        );
+        set_swatch(btn, enums::Color::from_rgb(r, g, b));
+        if let Some(swatch) = props::root_of(btn) {
+            notify::notify(&swatch);
+        }
+    });
+    notify::mark_notifying(&btn);
+    register_accessor(&btn, color_accessor());
+    btn
+}
+
+pub fn make_color_frame(color: enums::Color) -> frame::Frame {
+    let mut frame = frame::Frame::default();
+    frame.set_frame(enums::FrameType::BorderBox);
+    set_swatch(&mut frame, color);
+    register_accessor(&frame, color_accessor());
+    frame
+}
+
+fn color_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| Some(wid.color().to_hex_str()),
+        check: |_, value| style::parse_color(value).is_some(),
+        set: |wid, value| {
+            if let Some(color) = style::parse_color(value) {
+                set_swatch(&mut wid.clone(), color);
+            }
+        },
+    }
+}
+
+/// A color given as `#rrggbb` or by its HTML name, read back as `#rrggbb`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlColor(pub String);
+
+impl FlColor {
+    /// `None` if the color isn't a known HTML name nor `#rrggbb`
+    pub fn to_color(&self) -> Option<enums::Color> {
+        style::parse_color(&self.0)
+    }
+}
+
+impl From<enums::Color> for FlColor {
+    fn from(color: enums::Color) -> Self {
+        FlColor(color.to_hex_str())
+    }
+}
+
+impl fmt::Display for FlColor {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
 #[derive(Debug)]
 #[non_exhaustive]
 pub enum FltkFormError {
     FltkError(FltkErrorKind),
     Internal(FltkFormErrorKind),
     Unknown(String),
+    RejectedProps(Vec<String>),
 }
 
 unsafe impl Send for FltkFormError {}
@@ -220,6 +343,9 @@ impl fmt::Display for FltkFormError {
             FltkFormError::Internal(ref err) => write!(f, "An internal error occured {:?}", err),
             FltkFormError::Unknown(ref err) => write!(f, "An unknown error occurred {:?}", err),
             FltkFormError::FltkError(ref err) => write!(f, "an fltk error occured {:?}", err),
+            FltkFormError::RejectedProps(ref props) => {
+                write!(f, "The following properties were rejected {:?}", props)
+            }
         }
     }
 }
@@ -227,6 +353,22 @@ impl fmt::Display for FltkFormError {
 #[derive(Clone, Debug)]
 pub struct Form {
     grp: group::Group,
+    scroll: group::Scroll,
+    index: props::PropIndex,
+    state: Rc<RefCell<FormState>>,
+}
+
+type Regenerate = fn(&widget::Widget) -> Option<Box<dyn WidgetExt>>;
+
+#[derive(Debug, Default)]
+struct FormState {
+    layout: FormLayout,
+    style: FormStyle,
+    readonly: bool,
+    /// Reads the data back and generates its widgets again, set by `set_data()`/`set_data_view()`
+    regenerate: Option<Regenerate>,
+    /// The properties renamed by `rename_prop()`, renamed again by `refresh()`
+    renamed: Vec<(String, String)>,
 }
 
 impl Default for Form {
@@ -238,19 +380,80 @@ impl Default for Form {
 impl Form {
     pub fn new<S: Into<Option<&'static str>>>(x: i32, y: i32, w: i32, h: i32, label: S) -> Self {
         let grp = group::Group::new(x, y, w, h, label);
+        let mut scroll = group::Scroll::new(x, y, w, h, None);
+        scroll.set_type(group::ScrollType::Vertical);
+        scroll.end();
         grp.end();
-        Self { grp }
+        grp.resizable(&scroll);
+        let index = props::PropIndex::default();
+        scroll.resize_callback({
+            let index = index.clone();
+            move |_, _, _, _, _| {
+                if let Some(root) = index.root() {
+                    layout::fit_scroll(&root);
+                }
+            }
+        });
+        Self {
+            grp,
+            scroll,
+            index,
+            state: Rc::new(RefCell::new(FormState::default())),
+        }
     }
 
     pub fn default_fill() -> Self {
         Form::default().size_of_parent().center_of_parent()
     }
 
+    pub(crate) fn populate<F: FnOnce() -> Box<dyn WidgetExt>>(&mut self, gen: F) {
+        self.replace_content(|| Some(gen()));
+        self.state.borrow_mut().renamed.clear();
+    }
+
+    fn replace_content<F: FnOnce() -> Option<Box<dyn WidgetExt>>>(&mut self, gen: F) -> bool {
+        let collapsed: Vec<String> = self
+            .root()
+            .map(|root| layout::sections_of(&root))
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|(_, section)| layout::is_collapsed(section))
+            .map(|(path, _)| path)
+            .collect();
+        self.scroll.scroll_to(0, 0);
+        self.scroll.begin();
+        let w = layout::with_style(self.style(), || layout::with_layout(self.layout(), gen));
+        self.scroll.end();
+        let mut root = match w.and_then(|w| props::root_of(&*w)) {
+            Some(root) => root,
+            None => return false,
+        };
+        if let Some(old) = self.root() {
+            // `refresh()` may be running from the callback of a field
+            layout::delete_later(old);
+        }
+        let h = if root.h() > 0 {
+            root.h()
+        } else {
+            self.scroll.h()
+        };
+        root.resize(self.scroll.x(), self.scroll.y(), self.scroll.w(), h);
+        for (path, section) in layout::sections_of(&root) {
+            if collapsed.contains(&path) {
+                layout::set_collapsed(&section, true);
+            }
+        }
+        if self.is_readonly() {
+            layout::set_readonly(&root, true);
+        }
+        layout::fit_scroll(&root);
+        self.index.rebuild(Some(root));
+        true
+    }
+
     pub fn set_data<T: FltkForm>(&mut self, data: T) {
-        self.begin();
-        let mut w = data.generate();
-        w.resize(self.x(), self.y(), self.w(), self.h());
-        self.end();
+        self.populate(|| data.generate());
+        self.state.borrow_mut().regenerate = Some(|root| Some(T::from_widget(root)?.generate()));
     }
 
     pub fn from_data<T: FltkForm>(mut self, data: T) -> Self {
@@ -259,10 +462,8 @@ impl Form {
     }
 
     pub fn set_data_view<T: FltkForm>(&mut self, data: T) {
-        self.begin();
-        let mut w = data.view();
-        w.resize(self.x(), self.y(), self.w(), self.h());
-        self.end();
+        self.populate(|| data.view());
+        self.state.borrow_mut().regenerate = Some(|root| Some(T::from_widget(root)?.view()));
     }
 
     pub fn from_data_view<T: FltkForm>(mut self, data: T) -> Self {
@@ -270,143 +471,236 @@ impl Form {
         self
     }
 
-    pub fn get_prop(&self, prop: &str) -> Option<String> {
-        if let Some(child) = self.grp.child(0) {
-            if let Some(grp) = child.as_group() {
-                for child in grp.into_iter() {
-                    if child.label() == prop {
-                        let ptr = child.as_widget_ptr();
-                        let val = if is_ptr_of::<input::Input>(ptr) {
-                            1
-                        } else if is_ptr_of::<button::CheckButton>(ptr) {
-                            2
-                        } else if is_ptr_of::<menu::Choice>(ptr) {
-                            3
-                        } else {
-                            4
-                        };
-                        match val {
-                            1 => {
-                                let inp = input::Input::from_dyn_widget_ptr(ptr as _).unwrap();
-                                return Some(inp.value());
-                            }
-                            2 => {
-                                let inp =
-                                    button::CheckButton::from_dyn_widget_ptr(ptr as _).unwrap();
-                                return Some(inp.value().to_string());
-                            }
-                            3 => {
-                                let choice = menu::Choice::from_dyn_widget_ptr(ptr as _).unwrap();
-                                return choice.choice();
-                            }
-                            _ => {
-                                let wid = widget::Widget::from_dyn_widget_ptr(ptr as _).unwrap();
-                                return Some(wid.label());
-                            }
-                        }
-                    }
-                }
-                None
-            } else {
-                None
-            }
-        } else {
-            None
+    /// Generates the widgets again from their current values, keeping the collapsed sections,
+    /// the renamed properties and the scroll position. The data is read back with
+    /// `T::from_widget()`, so this fails if some values are invalid, or for a `T` which can't be
+    /// read back, like a struct with `&str` fields.
+    pub fn refresh(&mut self) -> Result<(), FltkFormError> {
+        let root = self.root().ok_or(FltkFormError::Internal(
+            FltkFormErrorKind::FailedToChangeData,
+        ))?;
+        let invalid = props::invalid_props(&root);
+        if !invalid.is_empty() {
+            return Err(FltkFormError::RejectedProps(invalid));
+        }
+        let regenerate = self
+            .state
+            .borrow()
+            .regenerate
+            .ok_or(FltkFormError::Internal(
+                FltkFormErrorKind::FailedToChangeData,
+            ))?;
+        let ypos = self.scroll.yposition();
+        if !self.replace_content(|| regenerate(&root)) {
+            return Err(FltkFormError::Internal(
+                FltkFormErrorKind::FailedToChangeData,
+            ));
+        }
+        let renamed = self.state.borrow().renamed.clone();
+        for (prop, new_name) in &renamed {
+            props::rename_prop(self.props_source(), prop, new_name);
         }
+        self.scroll.scroll_to(0, ypos);
+        self.redraw();
+        Ok(())
     }
 
-    pub fn set_prop(&mut self, prop: &str, value: &str) -> Result<(), FltkFormError> {
-        let mut found = false;
-        if let Some(child) = self.grp.child(0) {
-            if let Some(grp) = child.as_group() {
-                for child in grp.into_iter() {
-                    if child.label() == prop {
-                        found = true;
-                        let ptr = child.as_widget_ptr();
-                        let val = if is_ptr_of::<input::Input>(ptr) {
-                            1
-                        } else if is_ptr_of::<button::CheckButton>(ptr) {
-                            2
-                        } else if is_ptr_of::<menu::Choice>(ptr) {
-                            3
-                        } else {
-                            4
-                        };
-                        match val {
-                            1 => {
-                                let mut inp =
-                                    input::Input::from_dyn_widget_ptr(ptr as _).unwrap();
-                                inp.set_value(value);
-                            }
-                            2 => {
-                                let mut inp =
-                                    button::CheckButton::from_dyn_widget_ptr(ptr as _).unwrap();
-                                let v = value == "true";
-                                inp.set_value(v);
-                            }
-                            3 => {
-                                let mut choice =
-                                    menu::Choice::from_dyn_widget_ptr(ptr as _).unwrap();
-                                let idx = choice.find_index(value);
-                                choice.set_value(idx);
-                            }
-                            _ => (),
-                        }
-                    }
-                }
-            }
+    /// Whether the section of a nested struct is collapsed, nested sections being named like
+    /// `outer.inner`. Returns `false` if there's no such section.
+    pub fn is_collapsed(&self, section: &str) -> bool {
+        self.section(section)
+            .map(|section| layout::is_collapsed(&section))
+            .unwrap_or(false)
+    }
+
+    /// Collapses or expands the section of a nested struct, nested sections being named like
+    /// `outer.inner`
+    pub fn set_collapsed(&mut self, section: &str, collapsed: bool) -> Result<(), FltkFormError> {
+        let section = self.section(section).ok_or(FltkFormError::Internal(
+            FltkFormErrorKind::PropertyInexistent,
+        ))?;
+        layout::set_collapsed(&section, collapsed);
+        Ok(())
+    }
+
+    fn section(&self, path: &str) -> Option<widget::Widget> {
+        layout::sections_of(&self.root()?)
+            .into_iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, section)| section)
+    }
+
+    pub fn layout(&self) -> FormLayout {
+        self.state.borrow().layout
+    }
+
+    /// Sets the layout of the generated fields. The root struct is arranged again right away,
+    /// nested structs use it the next time data is set, unless they have a `#[form(layout = ...)]`
+    pub fn set_layout(&mut self, layout: FormLayout) {
+        self.state.borrow_mut().layout = layout;
+        if let Some(root) = self.root() {
+            layout::set_layout(&root, layout);
         }
-        if !found {
-            return Err(FltkFormError::Internal(
-                FltkFormErrorKind::PropertyInexistent,
-            ));
+    }
+
+    /// Scrolls the form so that the widget of `prop` is visible
+    pub fn scroll_to(&mut self, prop: &str) -> Result<(), FltkFormError> {
+        let wid = self.field(prop)?;
+        // leave room for labels placed above their field
+        let top = wid.y() - layout::ROW_HEIGHT;
+        let bottom = wid.y() + wid.h() + layout::MARGIN;
+        let ypos = self.scroll.yposition();
+        if top < self.scroll.y() {
+            let ypos = (ypos - (self.scroll.y() - top)).max(0);
+            self.scroll.scroll_to(0, ypos);
+        } else if bottom > self.scroll.y() + self.scroll.h() {
+            let ypos = ypos + bottom - (self.scroll.y() + self.scroll.h());
+            self.scroll.scroll_to(0, ypos);
         }
         Ok(())
     }
 
-    pub fn get_props(&self) -> HashMap<String, String> {
-        let mut temp = HashMap::new();
-        if let Some(c) = self.grp.child(0) {
-            if let Some(grp) = c.as_group() {
-                for child in grp.into_iter() {
-                    if !child.label().is_empty() {
-                        if let Some(prop) = self.get_prop(&child.label()) {
-                            temp.insert(child.label().clone(), prop);
-                        }
-                    }
-                }
-            }
+    /// Activates or deactivates the widget of `prop`, or the fields of a nested struct
+    pub fn set_enabled(&mut self, prop: &str, enabled: bool) -> Result<(), FltkFormError> {
+        layout::set_enabled(&self.field(prop)?, enabled);
+        Ok(())
+    }
+
+    /// Shows or hides the widget of `prop`, the following fields moving up to take its row
+    pub fn set_visible(&mut self, prop: &str, visible: bool) -> Result<(), FltkFormError> {
+        layout::set_visible(&self.field(prop)?, visible);
+        Ok(())
+    }
+
+    /// Makes the widget of `prop` read-only, or the fields of a nested struct.
+    /// Inputs keep showing their value, other widgets are deactivated.
+    /// The setting is lost when the widgets are generated again.
+    pub fn set_readonly(&mut self, prop: &str, readonly: bool) -> Result<(), FltkFormError> {
+        layout::set_readonly(&self.field(prop)?, readonly);
+        Ok(())
+    }
+
+    pub fn is_readonly(&self) -> bool {
+        self.state.borrow().readonly
+    }
+
+    /// Switches every field between edition and a read-only presentation like that of
+    /// `set_data_view()`, without generating the widgets again.
+    /// The setting is kept when data is set again.
+    pub fn set_form_readonly(&mut self, readonly: bool) {
+        self.state.borrow_mut().readonly = readonly;
+        if let Some(root) = self.root() {
+            layout::set_readonly(&root, readonly);
         }
-        temp
     }
 
-    pub fn rename_prop(&self, prop: &str, new_name: &str) {
-        if let Some(child) = self.grp.child(0) {
-            if let Some(grp) = child.as_group() {
-                for mut child in grp.into_iter() {
-                    if child.label() == prop {
-                        child.set_label(new_name);
-                    }
-                }
-            }
+    /// Calls `f` with the widget which changed each time the value of `prop` changes,
+    /// be it edited or set with `set_prop()`. For a nested struct or a list, any of its fields
+    /// or items changing is a change. Listeners are dropped when the widgets are generated again.
+    pub fn on_change<F: FnMut(&widget::Widget) + 'static>(
+        &mut self,
+        prop: &str,
+        f: F,
+    ) -> Result<(), FltkFormError> {
+        notify::on_change(&self.field(prop)?, f);
+        Ok(())
+    }
+
+    fn field(&self, prop: &str) -> Result<widget::Widget, FltkFormError> {
+        props::find_prop(&self.index, prop).ok_or(FltkFormError::Internal(
+            FltkFormErrorKind::PropertyInexistent,
+        ))
+    }
+
+    pub fn with_layout(mut self, layout: FormLayout) -> Self {
+        self.set_layout(layout);
+        self
+    }
+
+    pub fn style(&self) -> FormStyle {
+        self.state.borrow().style
+    }
+
+    /// Sets the fonts, colors and sizes of the generated widgets, restyling those already there
+    pub fn set_style(&mut self, style: FormStyle) {
+        self.state.borrow_mut().style = style;
+        self.scroll.set_frame(enums::FrameType::FlatBox);
+        self.scroll.set_color(style.background);
+        if let Some(root) = self.root() {
+            layout::set_style(&root, &style);
+            layout::fit_scroll(&root);
         }
+        self.scroll.redraw();
     }
 
-    pub fn get_widget(&self, prop: &str) -> Option<Box<dyn WidgetExt>> {
-        if let Some(grp) = self.as_group() {
-            for child in grp.into_iter() {
-                if child.label() == prop {
-                    return Some(Box::new(child));
-                }
-            }
-            None
-        } else if self.label() == prop {
-            let wid: widget::Widget = widget::Widget::from_dyn_widget_ptr(self.as_widget_ptr()).unwrap();
-            Some(Box::new(wid))
+    pub fn with_style(mut self, style: FormStyle) -> Self {
+        self.set_style(style);
+        self
+    }
+
+    /// Checks the values of every field, nested structs included, giving the invalid ones
+    /// the error color of the style. Returns the invalid fields as `RejectedProps`.
+    pub fn validate(&self) -> Result<(), FltkFormError> {
+        let invalid = self
+            .root()
+            .map(|root| layout::mark_invalid(&root, &self.style()))
+            .unwrap_or_default();
+        if invalid.is_empty() {
+            Ok(())
         } else {
-            None
+            Err(FltkFormError::RejectedProps(invalid))
         }
     }
+
+    fn props_source(&self) -> &props::PropIndex {
+        &self.index
+    }
+
+    pub(crate) fn root(&self) -> Option<widget::Widget> {
+        self.index.root()
+    }
+
+    /// Reads the form's content back into a `T`, `None` if some property holds an invalid value
+    pub fn get_data<T: FltkForm>(&self) -> Option<T> {
+        T::from_widget(&self.root()?)
+    }
+
+    /// Rebuilds the property index, needed only if the generated widgets were relabeled or
+    /// replaced without going through the `Form`
+    pub fn reindex(&mut self) {
+        self.index.rebuild(self.index.root());
+    }
+
+    pub fn get_prop(&self, prop: &str) -> Option<String> {
+        HasProps::get_prop(self, prop)
+    }
+
+    pub fn set_prop(&mut self, prop: &str, value: &str) -> Result<(), FltkFormError> {
+        HasProps::set_prop(self, prop, value)
+    }
+
+    pub fn get_props(&self) -> HashMap<String, String> {
+        HasProps::get_props(self)
+    }
+
+    /// Like `get_props()`, including the values of `FlSecret` and `#[form(secret)]` fields
+    pub fn get_props_with_secrets(&self) -> HashMap<String, String> {
+        props::get_props_with_secrets(self.props_source())
+    }
+
+    pub fn set_props(&mut self, props: &HashMap<String, String>) -> Result<(), FltkFormError> {
+        HasProps::set_props(self, props)
+    }
+
+    pub fn rename_prop(&self, prop: &str, new_name: &str) {
+        props::rename_prop(self.props_source(), prop, new_name);
+        let mut state = self.state.borrow_mut();
+        state.renamed.push((prop.to_string(), new_name.to_string()));
+    }
+
+    pub fn get_widget(&self, prop: &str) -> Option<Box<dyn WidgetExt>> {
+        HasProps::get_widget(self, prop)
+    }
 }
 
 fltk::widget_extends!(Form, group::Group, grp);
@@ -414,19 +708,176 @@ fltk::widget_extends!(Form, group::Group, grp);
 pub trait FltkForm {
     fn generate(&self) -> Box<dyn WidgetExt>;
     fn view(&self) -> Box<dyn WidgetExt>;
+    /// Reads the value back from a widget created by `generate()` or `view()`,
+    /// returns `None` if the widget holds an invalid value or the type can't be read back
+    fn from_widget(_wid: &widget::Widget) -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+    /// The value given to a new item of a `Vec<Self>`, and shown greyed out while an
+    /// `Option<Self>` is `None`. Without one, items can't be added and the option can't be set.
+    fn blank() -> Option<Self>
+    where
+        Self: Sized,
+    {
+        None
+    }
+    /// The widget of an `Option<Self>` field, by default a checkbox followed by the widget of
+    /// the value, the latter being greyed out while the checkbox is unchecked
+    fn generate_option(value: &Option<Self>) -> Box<dyn WidgetExt>
+    where
+        Self: Sized,
+    {
+        let blank = value.is_none().then(Self::blank).flatten();
+        let editable = value.is_some() || blank.is_some();
+        Box::new(option::make_row(value.is_some(), editable, || match value
+            .as_ref()
+            .or(blank.as_ref())
+        {
+            Some(value) => value.generate(),
+            None => Box::new(frame::Frame::default()),
+        }))
+    }
+    /// Like `generate_option()`, using `view()` and a checkbox which can't be toggled
+    fn view_option(value: &Option<Self>) -> Box<dyn WidgetExt>
+    where
+        Self: Sized,
+    {
+        let blank = value.is_none().then(Self::blank).flatten();
+        Box::new(option::make_row(value.is_some(), false, || {
+            match value.as_ref().or(blank.as_ref()) {
+                Some(value) => value.view(),
+                None => Box::new(frame::Frame::default()),
+            }
+        }))
+    }
+    /// Reads back an `Option<Self>` from a widget created by `generate_option()` or
+    /// `view_option()`, returns `None` if the value is set but invalid
+    fn option_from_widget(wid: &widget::Widget) -> Option<Option<Self>>
+    where
+        Self: Sized,
+    {
+        option::from_row(wid)
+    }
+    /// The widget of a `Vec<Self>` field, by default a list with buttons adding a blank item
+    /// and moving, duplicating or deleting each item
+    fn generate_vec(items: &[Self]) -> Box<dyn WidgetExt>
+    where
+        Self: Sized,
+    {
+        Box::new(list::make_list(
+            items.len(),
+            |i| items[i].generate(),
+            Some(list::ListOps {
+                make_item: make_blank::<Self>(),
+                duplicate: |item| Some(Self::from_widget(item)?.generate()),
+                movable: true,
+            }),
+        ))
+    }
+    /// Like `generate_vec()`, using `view()` and without buttons
+    fn view_vec(items: &[Self]) -> Box<dyn WidgetExt>
+    where
+        Self: Sized,
+    {
+        Box::new(list::make_list(items.len(), |i| items[i].view(), None))
+    }
+}
+
+pub(crate) fn make_blank<T: FltkForm>() -> Option<fn() -> Box<dyn WidgetExt>> {
+    T::blank()?;
+    Some(|| match T::blank() {
+        Some(blank) => blank.generate(),
+        None => Box::new(frame::Frame::default()),
+    })
+}
+
+/// The variants of a fieldless enum along with their names, in declaration order.
+/// Implemented by `#[derive(FltkForm)]` for enums, lets sets of them be shown as checkboxes.
+pub trait FormVariants: Sized {
+    fn variants() -> Vec<(&'static str, Self)>;
+}
+
+/// While the value is `None`, the greyed out widget is that of `T::blank()`
+impl<T: FltkForm> FltkForm for Option<T> {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        T::generate_option(self)
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        T::view_option(self)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        T::option_from_widget(wid)
+    }
+    fn blank() -> Option<Self> {
+        Some(None)
+    }
 }
 
 impl FltkForm for FlImage {
     fn generate(&self) -> Box<dyn WidgetExt> {
         let val = format!("{}", *self);
-        let i = make_image_frame(val.as_str());
+        let mut i = make_image_frame(val.as_str());
+        register_accessor(&i, image_accessor());
+        // clicking the image picks another one
+        i.handle(|f, ev| {
+            if ev != enums::Event::Push {
+                return false;
+            }
+            if let Some(path) = path::choose_image(&f.tooltip().unwrap_or_default()) {
+                if let Some(wid) = props::root_of(f) {
+                    props::apply(&wid, &path.to_string_lossy());
+                    notify::notify(&wid);
+                }
+            }
+            true
+        });
+        notify::mark_notifying(&i);
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let val = format!("{}", *self);
         let i = make_image_frame(val.as_str());
+        register_accessor(&i, image_accessor());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        Some(FlImage(wid.tooltip()?))
+    }
+}
+
+impl FltkForm for FlColor {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        let color = self.to_color().unwrap_or(enums::Color::White);
+        Box::new(make_color_button(color))
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        let color = self.to_color().unwrap_or(enums::Color::White);
+        Box::new(make_color_frame(color))
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        Some(FlColor(props::value_of(wid)?))
+    }
+    fn blank() -> Option<Self> {
+        Some(FlColor::from(enums::Color::White))
+    }
+}
+
+impl FltkForm for enums::Color {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        Box::new(make_color_button(*self))
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        Box::new(make_color_frame(*self))
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        style::parse_color(&props::value_of(wid)?)
+    }
+    fn blank() -> Option<Self> {
+        Some(enums::Color::White)
+    }
 }
 
 impl FltkForm for f64 {
@@ -434,14 +885,22 @@ impl FltkForm for f64 {
         let mut i = input::FloatInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::FloatInput, f64>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, f64>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for f32 {
@@ -449,14 +908,22 @@ impl FltkForm for f32 {
         let mut i = input::FloatInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::FloatInput, f32>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, f32>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for i32 {
@@ -464,14 +931,22 @@ impl FltkForm for i32 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, i32>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, i32>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for u32 {
@@ -479,14 +954,22 @@ impl FltkForm for u32 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, u32>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, u32>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for i64 {
@@ -494,14 +977,22 @@ impl FltkForm for i64 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, i64>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, i64>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for u64 {
@@ -509,14 +1000,22 @@ impl FltkForm for u64 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, u64>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, u64>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for isize {
@@ -524,14 +1023,22 @@ impl FltkForm for isize {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, isize>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, isize>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for usize {
@@ -539,14 +1046,22 @@ impl FltkForm for usize {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, usize>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, usize>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for i8 {
@@ -554,14 +1069,22 @@ impl FltkForm for i8 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, i8>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, i8>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for u8 {
@@ -569,14 +1092,22 @@ impl FltkForm for u8 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, u8>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, u8>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for i16 {
@@ -584,14 +1115,22 @@ impl FltkForm for i16 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, i16>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, i16>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for u16 {
@@ -599,27 +1138,43 @@ impl FltkForm for u16 {
         let mut i = input::IntInput::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<input::IntInput, u16>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         let val = format!("{:?}", *self);
         i.set_value(&val);
+        register_accessor(&i, ValueAccessor::text::<output::Output, u16>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for String {
     fn generate(&self) -> Box<dyn WidgetExt> {
         let mut i = input::Input::default();
         i.set_value(self);
+        register_accessor(&i, ValueAccessor::text::<input::Input, String>());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default();
         i.set_value(self);
+        register_accessor(&i, ValueAccessor::text::<output::Output, String>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
 }
 
 impl FltkForm for &str {
@@ -638,195 +1193,280 @@ impl FltkForm for bool {
         let mut i = button::CheckButton::default().with_align(enums::Align::Left);
         i.set_value(*self);
         i.clear_visible_focus();
+        register_accessor(&i, ValueAccessor::check_button());
         Box::new(i)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
         let mut i = output::Output::default().with_align(enums::Align::Left);
         i.set_value(&format!("{}", *self));
         i.clear_visible_focus();
+        register_accessor(&i, ValueAccessor::text::<output::Output, bool>());
         Box::new(i)
     }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::value_of(wid)?.parse().ok()
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::default())
+    }
+    fn generate_option(value: &Option<Self>) -> Box<dyn WidgetExt> {
+        Box::new(option::make_tri_state(*value))
+    }
+    fn view_option(value: &Option<Self>) -> Box<dyn WidgetExt> {
+        Box::new(option::make_tri_state_output(*value))
+    }
+    fn option_from_widget(wid: &widget::Widget) -> Option<Option<Self>> {
+        option::parse_bool(&props::value_of(wid)?)
+    }
 }
 
-impl<T> FltkForm for Vec<T>
-where
-    T: FltkForm,
-{
+/// Generated with buttons adding a blank item and moving, duplicating or deleting each item,
+/// unless `T` shows its `Vec`s differently through `FltkForm::generate_vec()`
+impl<T: FltkForm> FltkForm for Vec<T> {
     fn generate(&self) -> Box<dyn WidgetExt> {
-        let mut g = group::Pack::default();
-        g.set_spacing(5);
-        for v in self.iter() {
-            let mut w = v.generate();
-            w.set_align(enums::Align::Left);
-            w.set_size(w.w(), 30);
-        }
-        g.end();
-        Box::new(g)
+        T::generate_vec(self)
     }
     fn view(&self) -> Box<dyn WidgetExt> {
-        let mut g = group::Pack::default();
-        g.set_spacing(5);
-        for v in self.iter() {
-            let mut w = v.view();
-            w.set_align(enums::Align::Left);
-            w.set_size(w.w(), 30);
-        }
-        g.end();
-        Box::new(g)
+        T::view_vec(self)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        props::props_of(wid).iter().map(T::from_widget).collect()
+    }
+    fn blank() -> Option<Self> {
+        Some(Vec::new())
+    }
+    fn generate_vec(rows: &[Self]) -> Box<dyn WidgetExt> {
+        let lens: Vec<usize> = rows.iter().map(Vec::len).collect();
+        Box::new(grid::make_grid(
+            &lens,
+            |r, c| rows[r][c].generate(),
+            make_blank::<T>().map(|make_cell| grid::GridOps { make_cell }),
+        ))
+    }
+    fn view_vec(rows: &[Self]) -> Box<dyn WidgetExt> {
+        let lens: Vec<usize> = rows.iter().map(Vec::len).collect();
+        Box::new(grid::make_grid(&lens, |r, c| rows[r][c].view(), None))
     }
 }
 
-#[allow(clippy::borrowed_box)]
-fn rename_prop_(wid: &Box<dyn WidgetExt>, prop: &str, new_name: &str) {
-    if let Some(grp) = wid.as_group() {
-        for mut child in grp.into_iter() {
-            if child.label() == prop {
-                child.set_label(new_name);
-            }
-        }
+/// Shown as a row of the items, an array of arrays being a grid with a row per inner array
+impl<T: FltkForm, const N: usize> FltkForm for [T; N] {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        Box::new(grid::make_array(N, |i| self[i].generate()))
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        Box::new(grid::make_array(N, |i| self[i].view()))
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        let items: Option<Vec<T>> = props::props_of(wid).iter().map(T::from_widget).collect();
+        items?.try_into().ok()
+    }
+    fn blank() -> Option<Self> {
+        let items: Option<Vec<T>> = (0..N).map(|_| T::blank()).collect();
+        items?.try_into().ok()
     }
 }
 
-#[allow(clippy::borrowed_box)]
-fn get_prop_(wid: &Box<dyn WidgetExt>, prop: &str) -> Option<String> {
-    if let Some(grp) = wid.as_group() {
-        for child in grp.into_iter() {
-            if child.label() == prop {
-                let ptr = child.as_widget_ptr();
-                let val = if is_ptr_of::<input::Input>(ptr) {
-                    1
-                } else if is_ptr_of::<button::CheckButton>(ptr) {
-                    2
-                } else if is_ptr_of::<menu::Choice>(ptr) {
-                    3
-                } else {
-                    4
-                };
-                match val {
-                    1 => {
-                        let inp = input::Input::from_dyn_widget_ptr(ptr as _).unwrap();
-                        return Some(inp.value());
-                    }
-                    2 => {
-                        let inp = button::CheckButton::from_dyn_widget_ptr(ptr as _).unwrap();
-                        return Some(format!("{}", inp.value()));
-                    }
-                    3 => {
-                        let choice = menu::Choice::from_dyn_widget_ptr(ptr as _).unwrap();
-                        return choice.choice();
-                    }
-                    _ => {
-                        let wid = widget::Widget::from_dyn_widget_ptr(ptr as _).unwrap();
-                        return Some(wid.label());
-                    }
-                }
-            }
-        }
-        None
-    } else {
-        None
+/// Shown as a list of entries sorted by the text of their key, with buttons adding a blank
+/// entry and duplicating or deleting each entry. Duplicate keys are rejected.
+impl<K, V> FltkForm for HashMap<K, V>
+where
+    K: FromStr + fmt::Display + Eq + Hash,
+    V: FltkForm,
+{
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        Box::new(map::make_map::<K, V>(&sorted_entries(self.iter()), true))
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        Box::new(map::make_map::<K, V>(&sorted_entries(self.iter()), false))
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        let entries = map::entries_from_widget::<K, V>(wid)?;
+        let len = entries.len();
+        let map: Self = entries.into_iter().collect();
+        (map.len() == len).then_some(map)
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::new())
     }
 }
 
-#[allow(clippy::borrowed_box)]
-fn set_prop_(wid: &Box<dyn WidgetExt>, prop: &str, value: &str) -> Result<(), FltkFormError> {
-    let mut found = false;
-    if let Some(grp) = wid.as_group() {
-        for child in grp.into_iter() {
-            if child.label() == prop {
-                found = true;
-                let ptr = child.as_widget_ptr();
-                let val = if is_ptr_of::<input::Input>(ptr) {
-                    1
-                } else if is_ptr_of::<button::CheckButton>(ptr) {
-                    2
-                } else if is_ptr_of::<menu::Choice>(ptr) {
-                    3
-                } else {
-                    4
-                };
-                match val {
-                    1 => {
-                        let mut inp = input::Input::from_dyn_widget_ptr(ptr as _).unwrap();
-                        inp.set_value(value);
-                    }
-                    2 => {
-                        let mut inp = button::CheckButton::from_dyn_widget_ptr(ptr as _).unwrap();
-                        let v = value == "true";
-                        inp.set_value(v);
-                    }
-                    3 => {
-                        let mut choice = menu::Choice::from_dyn_widget_ptr(ptr as _).unwrap();
-                        let idx = choice.find_index(value);
-                        choice.set_value(idx);
-                    }
-                    _ => (),
-                }
-            }
-        }
+fn sorted_entries<'a, K: fmt::Display + 'a, V: 'a>(
+    entries: impl Iterator<Item = (&'a K, &'a V)>,
+) -> Vec<(String, &'a V)> {
+    let mut entries: Vec<(String, &V)> = entries.map(|(k, v)| (k.to_string(), v)).collect();
+    entries.sort_by(|a, b| a.0.cmp(&b.0));
+    entries
+}
+
+/// Like `HashMap`, the entries being in the order of their keys
+impl<K, V> FltkForm for BTreeMap<K, V>
+where
+    K: FromStr + fmt::Display + Ord,
+    V: FltkForm,
+{
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        let entries: Vec<(String, &V)> = self.iter().map(|(k, v)| (k.to_string(), v)).collect();
+        Box::new(map::make_map::<K, V>(&entries, true))
     }
-    if !found {
-        return Err(FltkFormError::Internal(
-            FltkFormErrorKind::PropertyInexistent,
-        ));
+    fn view(&self) -> Box<dyn WidgetExt> {
+        let entries: Vec<(String, &V)> = self.iter().map(|(k, v)| (k.to_string(), v)).collect();
+        Box::new(map::make_map::<K, V>(&entries, false))
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        let entries = map::entries_from_widget::<K, V>(wid)?;
+        let len = entries.len();
+        let map: Self = entries.into_iter().collect();
+        (map.len() == len).then_some(map)
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::new())
     }
-    Ok(())
 }
 
-#[allow(clippy::borrowed_box)]
-fn get_props_(wid: &Box<dyn WidgetExt>) -> HashMap<String, String> {
-    let mut temp = HashMap::new();
-    if let Some(grp) = wid.as_group() {
-        for child in grp.into_iter() {
-            if !child.label().is_empty() {
-                if let Some(prop) = get_prop_(wid, &child.label()) {
-                    temp.insert(child.label().clone(), prop);
+macro_rules! impl_tuple {
+    ($(($($t:ident $i:tt),+))+) => {
+        $(
+            /// Shown inline on one row, each element after its position
+            impl<$($t: FltkForm),+> FltkForm for ($($t,)+) {
+                fn generate(&self) -> Box<dyn WidgetExt> {
+                    let len = [$($i),+].len();
+                    Box::new(tuple::make_tuple(len, |i| match i {
+                        $($i => self.$i.generate(),)+
+                        _ => unreachable!(),
+                    }))
+                }
+                fn view(&self) -> Box<dyn WidgetExt> {
+                    let len = [$($i),+].len();
+                    Box::new(tuple::make_tuple(len, |i| match i {
+                        $($i => self.$i.view(),)+
+                        _ => unreachable!(),
+                    }))
+                }
+                fn from_widget(wid: &widget::Widget) -> Option<Self> {
+                    let items = props::props_of(wid);
+                    Some(($($t::from_widget(items.get($i)?)?,)+))
+                }
+                fn blank() -> Option<Self> {
+                    Some(($($t::blank()?,)+))
                 }
             }
-        }
+        )+
+    };
+}
+
+impl_tuple! {
+    (A 0)
+    (A 0, B 1)
+    (A 0, B 1, C 2)
+    (A 0, B 1, C 2, D 3)
+    (A 0, B 1, C 2, D 3, E 4)
+    (A 0, B 1, C 2, D 3, E 4, F 5)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10)
+    (A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11)
+}
+
+/// Shown as a checkbox per variant, or as a multi-select browser for enums with many variants
+impl<T: FormVariants + Eq + Hash> FltkForm for HashSet<T> {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        set::make_enum_set(|value: &T| self.contains(value), true)
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        set::make_enum_set(|value: &T| self.contains(value), false)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        set::enum_set_from_widget::<T, Self>(wid)
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::new())
     }
-    temp
 }
 
-#[allow(clippy::borrowed_box)]
-fn get_widget_(wid: &Box<dyn WidgetExt>, prop: &str) -> Option<Box<dyn WidgetExt>> {
-    if let Some(grp) = wid.as_group() {
-        for child in grp.into_iter() {
-            if child.label() == prop {
-                return Some(Box::new(child));
-            }
-        }
-        None
-    } else if wid.label() == prop {
-        let wid: widget::Widget = widget::Widget::from_dyn_widget_ptr(wid.as_widget_ptr()).unwrap();
-        Some(Box::new(wid))
-    } else {
-        None
+/// Like `HashSet`
+impl<T: FormVariants + Ord> FltkForm for BTreeSet<T> {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        set::make_enum_set(|value: &T| self.contains(value), true)
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        set::make_enum_set(|value: &T| self.contains(value), false)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        set::enum_set_from_widget::<T, Self>(wid)
+    }
+    fn blank() -> Option<Self> {
+        Some(Self::new())
     }
 }
 
+/// Access by name to the values of generated widgets, through a `Form`, a `Wizard`,
+/// the `Box<dyn WidgetExt>` returned by `generate()` or `view()`, or a group or window holding
+/// it. A group or window looks up the properties of the first generated widget among its
+/// descendants.
 pub trait HasProps {
     fn get_prop(&self, prop: &str) -> Option<String>;
     fn set_prop(&mut self, prop: &str, value: &str) -> Result<(), FltkFormError>;
     fn get_props(&self) -> HashMap<String, String>;
+    /// Sets several properties at once. Every entry is validated before any of them is applied,
+    /// if one is rejected, nothing changes and all rejected properties are returned.
+    /// A property is applied before those nested in it, so `list[0]` or `outer.a` override the
+    /// item or field given by `list` or `outer`. Lists and maps must be given as many items as
+    /// they hold, a snapshot from `get_props()` no longer applies once items were added or removed.
+    fn set_props(&mut self, props: &HashMap<String, String>) -> Result<(), FltkFormError>;
+    /// Renames a property, unless another property is already named `new_name`
     fn rename_prop(&mut self, prop: &str, new_name: &str);
     fn get_widget(&self, prop: &str) -> Option<Box<dyn WidgetExt>>;
 }
 
-impl HasProps for Box<dyn WidgetExt> {
-    fn get_prop(&self, prop: &str) -> Option<String> {
-        get_prop_(self, prop)
-    }
-    fn set_prop(&mut self, prop: &str, value: &str) -> Result<(), FltkFormError> {
-        set_prop_(self, prop, value)
-    }
-    fn get_props(&self) -> HashMap<String, String> {
-        get_props_(self)
-    }
-    fn rename_prop(&mut self, prop: &str, new_name: &str) {
-        rename_prop_(self, prop, new_name);
-    }
-    fn get_widget(&self, prop: &str) -> Option<Box<dyn WidgetExt>> {
-        get_widget_(self, prop)
-    }
+macro_rules! impl_has_props {
+    ($($t:ty),+ => |$this:ident| $src:expr) => {$(
+        impl HasProps for $t {
+            fn get_prop(&self, prop: &str) -> Option<String> {
+                let $this = &*self;
+                props::get_prop(&$src, prop)
+            }
+            fn set_prop(&mut self, prop: &str, value: &str) -> Result<(), FltkFormError> {
+                let $this = &*self;
+                props::set_prop(&$src, prop, value)
+            }
+            fn get_props(&self) -> HashMap<String, String> {
+                let $this = &*self;
+                props::get_props(&$src)
+            }
+            fn set_props(&mut self, props: &HashMap<String, String>) -> Result<(), FltkFormError> {
+                let $this = &*self;
+                props::set_props(&$src, props)
+            }
+            fn rename_prop(&mut self, prop: &str, new_name: &str) {
+                let $this = &*self;
+                props::rename_prop(&$src, prop, new_name);
+            }
+            fn get_widget(&self, prop: &str) -> Option<Box<dyn WidgetExt>> {
+                let $this = &*self;
+                props::get_widget(&$src, prop)
+            }
+        }
+    )+};
 }
+
+impl_has_props!(Form => |form| form.props_source());
+impl_has_props!(Wizard => |wizard| wizard.props_source());
+impl_has_props!(Box<dyn WidgetExt> => |wid| props::root_of(&**wid));
+impl_has_props!(
+    group::Group,
+    group::Pack,
+    group::Scroll,
+    group::Tabs,
+    group::Tile,
+    group::Wizard,
+    group::Flex,
+    group::Grid,
+    window::SingleWindow,
+    window::DoubleWindow,
+    window::MenuWindow,
+    window::OverlayWindow
+    => |grp| props::generated_root(grp)
+);
@@ -0,0 +1,278 @@
+//! `Vec<T>` fields, and the entries of maps. When generated for edition, each item is followed
+//! by buttons moving it up or down, duplicating and deleting it, and a last button adds a
+//! blank item, if the type of the items has one.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, notify, register_accessor};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+
+#[derive(Clone, Copy)]
+pub(crate) struct ListOps {
+    /// The widget of a new item, `None` if items can't be added
+    pub(crate) make_item: Option<fn() -> Box<dyn WidgetExt>>,
+    /// The widget of a copy of an item, `None` if the item holds an invalid value
+    pub(crate) duplicate: fn(&widget::Widget) -> Option<Box<dyn WidgetExt>>,
+    /// Whether the items have buttons moving them up and down
+    pub(crate) movable: bool,
+}
+
+thread_local! {
+    static LISTS: RefCell<WidgetMap<Option<ListOps>>> = RefCell::new(WidgetMap::new());
+}
+
+pub(crate) fn is_list(wid: &widget::Widget) -> bool {
+    LISTS.with(|map| map.borrow_mut().get(wid).is_some())
+}
+
+pub(crate) fn mark_list(wid: &dyn WidgetExt) {
+    LISTS.with(|map| map.borrow_mut().insert(wid, None));
+}
+
+fn ops_of(wid: &widget::Widget) -> Option<ListOps> {
+    LISTS.with(|map| map.borrow_mut().get(wid)).flatten()
+}
+
+pub(crate) fn make_list<F: FnMut(usize) -> Box<dyn WidgetExt>>(
+    len: usize,
+    mut make: F,
+    ops: Option<ListOps>,
+) -> group::Pack {
+    let mut pack = group::Pack::default();
+    pack.set_spacing(layout::SPACING);
+    pack.end();
+    LISTS.with(|map| map.borrow_mut().insert(&pack, ops));
+    for i in 0..len {
+        match ops {
+            Some(ops) => {
+                add_row(&mut pack, i as i32, || Some(make(i)), ops);
+            }
+            None => {
+                pack.begin();
+                let mut w = make(i);
+                pack.end();
+                w.set_align(enums::Align::Left);
+                let h = if w.h() > 0 { w.h() } else { layout::ROW_HEIGHT };
+                w.set_size(w.w(), h);
+            }
+        }
+    }
+    if let Some((ops, make_item)) = ops.and_then(|ops| Some((ops, ops.make_item?))) {
+        pack.begin();
+        let mut add = control("@+", "Add");
+        pack.end();
+        add.set_size(layout::ROW_HEIGHT, layout::ROW_HEIGHT);
+        add.set_callback({
+            let mut pack = pack.clone();
+            move |_| {
+                let items = items_of(&pack).len() as i32;
+                add_row(&mut pack, items, || Some(make_item()), ops);
+                changed(&pack, items);
+            }
+        });
+    }
+    if let Some(pack) = props::root_of(&pack) {
+        fit(&pack);
+    }
+    register_accessor(&pack, ValueAccessor::list());
+    if ops.is_some() {
+        update_duplicates(&pack);
+        let list = pack.clone();
+        notify::on_change(&pack, move |_| update_duplicates(&list));
+    }
+    pack
+}
+
+pub(crate) fn control(symbol: &str, tooltip: &str) -> button::Button {
+    let mut btn = button::Button::default().with_label(symbol);
+    btn.set_tooltip(tooltip);
+    btn.clear_visible_focus();
+    props::mark_container(&btn);
+    btn
+}
+
+fn items_of(pack: &group::Pack) -> Vec<group::Flex> {
+    (0..pack.children())
+        .filter_map(|i| pack.child(i))
+        .filter_map(|child| group::Flex::from_dyn_widget_ptr(child.as_widget_ptr() as _))
+        .collect()
+}
+
+fn add_row<F: FnOnce() -> Option<Box<dyn WidgetExt>>>(
+    pack: &mut group::Pack,
+    index: i32,
+    make: F,
+    ops: ListOps,
+) -> bool {
+    let built = layout::with_current(pack, || {
+        let mut item = make()?;
+        let mut row = group::Flex::default().row();
+        // the item was made first, in the pack
+        if let Some(item) = props::root_of(&*item) {
+            row.add(&item);
+        }
+        item.set_align(enums::Align::Left);
+        let moves = ops
+            .movable
+            .then(|| [control("@8->", "Move up"), control("@2->", "Move down")]);
+        let (dup, del) = (control("@filenew", "Duplicate"), control("@9+", "Delete"));
+        row.end();
+        Some((row, item, moves, dup, del))
+    });
+    let (mut row, item, moves, mut dup, mut del) = match built {
+        Some(built) => built,
+        None => return false,
+    };
+    pack.insert(&row, index);
+    props::mark_container(&row);
+    row.set_pad(layout::MARGIN);
+    for btn in moves.iter().flatten().chain([&dup, &del]) {
+        row.fixed(btn, layout::ROW_HEIGHT);
+    }
+    let h = if item.h() > 0 {
+        item.h()
+    } else {
+        layout::ROW_HEIGHT
+    };
+    row.resize(row.x(), row.y(), row.w(), h);
+    if let Some(mut item) = props::root_of(&*item) {
+        layout::apply_parent_style(&mut item);
+    }
+    if let Some([mut up, mut down]) = moves {
+        up.set_callback({
+            let (mut pack, row) = (pack.clone(), row.clone());
+            move |_| {
+                let i = pack.find(&row);
+                if i > 0 {
+                    pack.insert(&row, i - 1);
+                    changed(&pack, i - 1);
+                }
+            }
+        });
+        down.set_callback({
+            let (mut pack, row) = (pack.clone(), row.clone());
+            move |_| {
+                let i = pack.find(&row);
+                if i + 1 < items_of(&pack).len() as i32 {
+                    // the row is removed before being inserted again
+                    pack.insert(&row, i + 2);
+                    changed(&pack, i + 1);
+                }
+            }
+        });
+    }
+    dup.set_callback({
+        let (mut pack, row) = (pack.clone(), row.clone());
+        move |_| {
+            let i = pack.find(&row);
+            if let Some(item) = row.child(0) {
+                if add_row(&mut pack, i + 1, || (ops.duplicate)(&item), ops) {
+                    changed(&pack, i + 1);
+                }
+            }
+        }
+    });
+    del.set_callback({
+        let (pack, row) = (pack.clone(), row.clone());
+        move |_| {
+            let i = pack.find(&row);
+            layout::delete_later(row.clone());
+            changed(&pack, i.min(items_of(&pack).len() as i32 - 1));
+        }
+    });
+    true
+}
+
+// Duplicate is only active while the item is valid, and while Delete is
+fn update_duplicates(pack: &group::Pack) {
+    for row in items_of(pack) {
+        let n = row.children();
+        if let (Some(item), Some(mut dup), Some(del)) =
+            (row.child(0), row.child(n - 2), row.child(n - 1))
+        {
+            if del.active() && props::is_valid_field(&item) {
+                dup.activate();
+            } else {
+                dup.deactivate();
+            }
+        }
+    }
+}
+
+pub(crate) fn fit(list: &widget::Widget) {
+    let mut pack = match group::Pack::from_dyn_widget_ptr(list.as_widget_ptr() as _) {
+        Some(pack) => pack,
+        None => return,
+    };
+    let editable = ops_of(list).is_some();
+    let mut h = 0;
+    for i in 0..pack.children() {
+        if let Some(mut child) = pack.child(i) {
+            // the row of an item of an editable list
+            if let Some(item) = child
+                .as_group()
+                .filter(|_| editable)
+                .and_then(|row| row.child(0))
+            {
+                let item_h = if item.h() > 0 {
+                    item.h()
+                } else {
+                    layout::ROW_HEIGHT
+                };
+                child.set_size(child.w(), item_h);
+            }
+            if child.visible() {
+                h += child.h() + layout::SPACING;
+            }
+        }
+    }
+    pack.set_size(pack.w(), (h - layout::SPACING).max(layout::ROW_HEIGHT));
+    layout::set_preferred_height(list, pack.h());
+}
+
+fn changed(pack: &group::Pack, index: i32) {
+    let list = match props::root_of(pack) {
+        Some(list) => list,
+        None => return,
+    };
+    if notify::is_notifying(&list) {
+        notify::watch(&list);
+    }
+    fit(&list);
+    layout::relayout(&list);
+    if let Some(item) = items_of(pack)
+        .get(index.max(0) as usize)
+        .and_then(|row| row.child(0))
+    {
+        item.clone().take_focus().ok();
+    }
+    list.clone().redraw();
+    notify::notify(&list);
+}
+
+pub(crate) fn set_editable(list: &widget::Widget, editable: bool) {
+    let pack = match group::Pack::from_dyn_widget_ptr(list.as_widget_ptr() as _) {
+        Some(pack) if ops_of(list).is_some() => pack,
+        _ => return,
+    };
+    let mut controls = vec![];
+    for i in 0..pack.children() {
+        match pack.child(i) {
+            Some(child) if child.as_group().is_some() => {
+                if let Some(row) = child.as_group() {
+                    controls.extend((1..row.children()).filter_map(|j| row.child(j)));
+                }
+            }
+            Some(child) => controls.push(child),
+            None => (),
+        }
+    }
+    for mut control in controls {
+        if editable {
+            control.activate();
+        } else {
+            control.deactivate();
+        }
+    }
+    update_duplicates(&pack);
+}
@@ -0,0 +1,270 @@
+//! `HashMap` and `BTreeMap` fields, shown as a list of entries each holding an input for the key
+//! and the widget of the value. A key which doesn't parse or is already used by another entry
+//! gets the error color as it is typed.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, list, notify, register_accessor, FltkForm, FormStyle};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+use std::fmt::Display;
+use std::str::FromStr;
+
+type Normalize = fn(&str) -> Option<String>;
+
+thread_local! {
+    static MAPS: RefCell<WidgetMap<Normalize>> = RefCell::new(WidgetMap::new());
+}
+
+pub(crate) fn is_map(wid: &widget::Widget) -> bool {
+    MAPS.with(|map| map.borrow_mut().get(wid).is_some())
+}
+
+fn normalize<K: FromStr + Display>(key: &str) -> Option<String> {
+    key.parse::<K>().ok().map(|key| key.to_string())
+}
+
+pub(crate) fn make_map<K, V>(entries: &[(String, &V)], editable: bool) -> group::Pack
+where
+    K: FromStr + Display,
+    V: FltkForm,
+{
+    let ops = editable.then(|| list::ListOps {
+        make_item: V::blank().map(|_| make_blank_entry::<V> as fn() -> Box<dyn WidgetExt>),
+        duplicate: |item| {
+            let (key, value) = parts(item)?;
+            let value = V::from_widget(&value)?.generate();
+            Some(Box::new(make_entry(&props::value_of(&key)?, value, true)))
+        },
+        movable: false,
+    });
+    let pack = list::make_list(
+        entries.len(),
+        |i| {
+            let (key, value) = &entries[i];
+            let value = if editable {
+                value.generate()
+            } else {
+                value.view()
+            };
+            Box::new(make_entry(key, value, editable))
+        },
+        ops,
+    );
+    MAPS.with(|map| map.borrow_mut().insert(&pack, normalize::<K>));
+    register_accessor(&pack, map_accessor());
+    if editable {
+        let map = pack.clone();
+        notify::on_change(&pack, move |_| {
+            if let Some(map) = props::root_of(&map) {
+                let style = layout::parent_style(&map);
+                mark_keys(&map, &style);
+            }
+        });
+    }
+    pack
+}
+
+fn make_blank_entry<V: FltkForm>() -> Box<dyn WidgetExt> {
+    let value = match V::blank() {
+        Some(value) => value.generate(),
+        None => Box::new(frame::Frame::default()),
+    };
+    Box::new(make_entry("", value, true))
+}
+
+fn make_entry(key: &str, value: Box<dyn WidgetExt>, editable: bool) -> group::Flex {
+    let mut entry = group::Flex::default().row();
+    let input = if editable {
+        let mut i = input::Input::default();
+        i.set_value(key);
+        props::root_of(&i)
+    } else {
+        let mut o = output::Output::default();
+        o.set_value(key);
+        props::root_of(&o)
+    };
+    entry.end();
+    entry.set_pad(layout::MARGIN);
+    if let Some(mut input) = input {
+        input.set_tooltip("Key");
+        register_accessor(&input, key_accessor());
+    }
+    // the value was generated first, in the group being built
+    if let Some(value) = props::root_of(&*value) {
+        entry.add(&value);
+    }
+    let h = if value.h() > 0 {
+        value.h()
+    } else {
+        layout::ROW_HEIGHT
+    };
+    entry.resize(entry.x(), entry.y(), entry.w(), h);
+    register_accessor(&entry, entry_accessor());
+    entry
+}
+
+fn parts(entry: &widget::Widget) -> Option<(widget::Widget, widget::Widget)> {
+    let entry = entry.as_group()?;
+    Some((entry.child(0)?, entry.child(1)?))
+}
+
+fn map_of(key: &widget::Widget) -> Option<widget::Widget> {
+    let mut parent = key.parent();
+    while let Some(grp) = parent {
+        let wid = props::root_of(&grp)?;
+        if is_map(&wid) {
+            return Some(wid);
+        }
+        parent = grp.parent();
+    }
+    None
+}
+
+fn normalize_of(map: &widget::Widget) -> Option<Normalize> {
+    MAPS.with(|maps| maps.borrow_mut().get(map))
+}
+
+pub(crate) fn entries(map: &widget::Widget) -> Vec<(widget::Widget, widget::Widget)> {
+    props::props_of(map).iter().filter_map(parts).collect()
+}
+
+pub(crate) fn find_value(map: &widget::Widget, key: &str) -> Option<widget::Widget> {
+    let normalize = normalize_of(map)?;
+    let key = normalize(key)?;
+    entries(map)
+        .into_iter()
+        .find(|(k, _)| props::value_of(k).and_then(|k| normalize(&k)) == Some(key.clone()))
+        .map(|(_, value)| value)
+}
+
+pub(crate) fn entries_from_widget<K: FromStr, V: FltkForm>(
+    map: &widget::Widget,
+) -> Option<Vec<(K, V)>> {
+    entries(map)
+        .iter()
+        .map(|(key, value)| Some((props::value_of(key)?.parse().ok()?, V::from_widget(value)?)))
+        .collect()
+}
+
+fn is_duplicate(key: &widget::Widget, value: &str) -> bool {
+    let map = match map_of(key) {
+        Some(map) => map,
+        None => return false,
+    };
+    let normalize = match normalize_of(&map) {
+        Some(normalize) => normalize,
+        None => return false,
+    };
+    let value = normalize(value);
+    entries(&map).iter().any(|(other, _)| {
+        other.as_widget_ptr() != key.as_widget_ptr()
+            && props::value_of(other).and_then(|other| normalize(&other)) == value
+    })
+}
+
+pub(crate) fn mark_keys(map: &widget::Widget, style: &FormStyle) {
+    if !is_map(map) {
+        return;
+    }
+    for (key, _) in entries(map) {
+        layout::set_error_color(&key, style, !props::is_valid_field(&key));
+    }
+}
+
+fn key_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| input::Input::from_dyn_widget_ptr(wid.as_widget_ptr() as _).map(|i| i.value()),
+        check: |wid, value| {
+            let parses = map_of(wid)
+                .and_then(|map| normalize_of(&map))
+                .is_none_or(|normalize| normalize(value).is_some());
+            parses && !is_duplicate(wid, value)
+        },
+        set: |wid, value| {
+            if let Some(mut i) = input::Input::from_dyn_widget_ptr(wid.as_widget_ptr() as _) {
+                i.set_value(value);
+            }
+        },
+    }
+}
+
+fn entry_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| {
+            let (key, value) = parts(wid)?;
+            Some(format!(
+                "{}: {}",
+                props::quote(&props::value_of(&key)?),
+                props::quote(&props::value_of(&value)?)
+            ))
+        },
+        check: |wid, value| match (parts(wid), props::split_pair(value)) {
+            (Some((key, value_wid)), Some((k, v))) => {
+                props::is_valid(&key, &k) && props::is_valid(&value_wid, &v)
+            }
+            _ => false,
+        },
+        set: |wid, value| {
+            if let (Some((key, value_wid)), Some((k, v))) = (parts(wid), props::split_pair(value)) {
+                props::apply(&key, &k);
+                props::apply(&value_wid, &v);
+            }
+        },
+    }
+}
+
+fn map_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| {
+            let items: Option<Vec<String>> =
+                props::props_of(wid).iter().map(props::value_of).collect();
+            Some(format!("{{{}}}", items?.join(", ")))
+        },
+        check: |wid, value| {
+            let (items, normalize) = match (props::split_pairs(value), normalize_of(wid)) {
+                (Some(items), Some(normalize)) => (items, normalize),
+                _ => return false,
+            };
+            let current = entries(wid);
+            let keys: Option<Vec<String>> = items.iter().map(|(k, _)| normalize(k)).collect();
+            let keys = match keys {
+                Some(keys) => keys,
+                None => return false,
+            };
+            items.len() == current.len()
+                && (0..keys.len()).all(|i| !keys[..i].contains(&keys[i]))
+                && items
+                    .iter()
+                    .zip(current.iter())
+                    .all(|((_, v), (_, value))| props::is_valid(value, v))
+        },
+        set: |wid, value| {
+            for ((k, v), (key, value)) in props::split_pairs(value)
+                .unwrap_or_default()
+                .into_iter()
+                .zip(entries(wid))
+            {
+                props::apply(&key, &k);
+                props::apply(&value, &v);
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalizes_keys_to_their_parsed_text() {
+        assert_eq!(normalize::<u32>("1"), Some("1".to_string()));
+        assert_eq!(normalize::<u32>("01"), normalize::<u32>("1"));
+        assert_eq!(normalize::<i32>("+1"), normalize::<i32>("1"));
+        assert_eq!(normalize::<f64>("1.0"), normalize::<f64>("1"));
+        assert_eq!(normalize::<String>("01"), Some("01".to_string()));
+        assert_ne!(normalize::<String>("01"), normalize::<String>("1"));
+        assert_eq!(normalize::<u32>("x"), None);
+        assert_eq!(normalize::<u32>(""), None);
+        assert_eq!(normalize::<u32>("-1"), None);
+    }
+}
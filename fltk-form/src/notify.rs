@@ -0,0 +1,97 @@
+//! Change notifications of the generated widgets, sent when the user edits a field
+//! and when its value is set through `HasProps`.
+
+use crate::props::{self, WidgetMap};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+type Listeners = Rc<RefCell<Vec<Box<dyn FnMut(&widget::Widget)>>>>;
+
+thread_local! {
+    static LISTENERS: RefCell<WidgetMap<Listeners>> = RefCell::new(WidgetMap::new());
+    static NOTIFYING: RefCell<WidgetMap<()>> = RefCell::new(WidgetMap::new());
+    static VALUES: RefCell<WidgetMap<String>> = RefCell::new(WidgetMap::new());
+}
+
+pub(crate) fn mark_notifying(wid: &dyn WidgetExt) {
+    NOTIFYING.with(|map| map.borrow_mut().insert(wid, ()));
+}
+
+pub(crate) fn is_notifying(wid: &widget::Widget) -> bool {
+    NOTIFYING.with(|map| map.borrow_mut().get(wid).is_some())
+}
+
+/// Calls `f` with the widget of a field each time its value changes, be it edited by the user
+/// or set through `HasProps`. Listening to a nested struct or a list is listening to all of its
+/// fields or items, `f` being given the widget which changed.
+pub fn on_change<F: FnMut(&widget::Widget) + 'static>(wid: &dyn WidgetExt, f: F) {
+    let listeners = match LISTENERS.with(|map| map.borrow_mut().get(wid)) {
+        Some(listeners) => listeners,
+        None => {
+            let listeners = Listeners::default();
+            LISTENERS.with(|map| map.borrow_mut().insert(wid, listeners.clone()));
+            listeners
+        }
+    };
+    listeners.borrow_mut().push(Box::new(f));
+    if let Some(root) = props::root_of(wid) {
+        watch(&root);
+    }
+}
+
+pub(crate) fn watch(wid: &widget::Widget) {
+    if wid.as_group().is_some() {
+        mark_notifying(wid);
+        for field in props::props_of(wid) {
+            watch(&field);
+        }
+        return;
+    }
+    if is_notifying(wid) {
+        return;
+    }
+    remember(wid);
+    let mut w = wid.clone();
+    w.handle(|w, ev| {
+        if !matches!(
+            ev,
+            enums::Event::Move | enums::Event::Enter | enums::Event::Leave | enums::Event::NoEvent
+        ) && changed(w)
+        {
+            notify(w);
+        }
+        false
+    });
+    mark_notifying(wid);
+}
+
+fn remember(wid: &widget::Widget) {
+    if let Some(value) = props::value_of(wid) {
+        VALUES.with(|map| map.borrow_mut().insert(wid, value));
+    }
+}
+
+fn changed(wid: &widget::Widget) -> bool {
+    let last = VALUES.with(|map| map.borrow_mut().get(wid));
+    let value = props::value_of(wid);
+    value.is_some() && value != last
+}
+
+pub(crate) fn notify(wid: &widget::Widget) {
+    // a value set through `HasProps` isn't reported again by the next event
+    if VALUES.with(|map| map.borrow_mut().get(wid).is_some()) {
+        remember(wid);
+    }
+    let mut current = Some(wid.clone());
+    while let Some(w) = current {
+        if let Some(listeners) = LISTENERS.with(|map| map.borrow_mut().get(&w)) {
+            if let Ok(mut listeners) = listeners.try_borrow_mut() {
+                for f in listeners.iter_mut() {
+                    f(wid);
+                }
+            }
+        }
+        current = w.parent().and_then(|grp| props::root_of(&grp));
+    }
+}
@@ -0,0 +1,236 @@
+//! `Option<T>` fields: a checkbox enabling the widget of the value,
+//! or a choice between `None`, `true` and `false` for `Option<bool>`.
+//! Their value is written `None` or `Some(value)`, a bare `value` being accepted by `set_prop`,
+//! quoted like `"None"` to be taken as a value.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, notify, register_accessor, FltkForm};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+
+const UNSET: &str = "None";
+
+thread_local! {
+    static ROWS: RefCell<WidgetMap<()>> = RefCell::new(WidgetMap::new());
+}
+
+pub(crate) fn inner_of(wid: &widget::Widget) -> Option<widget::Widget> {
+    if ROWS.with(|map| map.borrow_mut().get(wid).is_none()) {
+        return None;
+    }
+    row_parts(wid).map(|(_, inner)| inner)
+}
+
+pub(crate) fn parse(value: &str) -> Option<String> {
+    let value = value.trim();
+    if value == UNSET {
+        return None;
+    }
+    match value
+        .strip_prefix("Some(")
+        .and_then(|inner| inner.strip_suffix(')'))
+    {
+        Some(inner) => Some(inner.to_string()),
+        None => Some(props::unquote(value)),
+    }
+}
+
+fn format(value: Option<&str>) -> String {
+    match value {
+        Some(value) => format!("Some({})", value),
+        None => UNSET.to_string(),
+    }
+}
+
+pub(crate) fn make_row<F: FnOnce() -> Box<dyn WidgetExt>>(
+    set: bool,
+    editable: bool,
+    make: F,
+) -> group::Flex {
+    let mut row = group::Flex::default().row();
+    let mut check = button::CheckButton::default();
+    check.set_value(set);
+    check.clear_visible_focus();
+    let inner = make();
+    row.end();
+    row.set_pad(layout::MARGIN);
+    row.fixed(&check, layout::ROW_HEIGHT);
+    let h = if inner.h() > 0 {
+        inner.h()
+    } else {
+        layout::ROW_HEIGHT
+    };
+    row.resize(row.x(), row.y(), row.w(), h);
+    let inner = props::root_of(&*inner);
+    if let Some(inner) = inner.as_ref() {
+        layout::set_enabled(inner, set);
+    }
+    if editable {
+        check.set_callback(move |check| {
+            if let Some(inner) = inner.as_ref() {
+                layout::set_enabled(inner, check.value());
+            }
+            if let Some(check) = props::root_of(check) {
+                notify::notify(&check);
+            }
+        });
+        notify::mark_notifying(&check);
+    } else {
+        check.deactivate();
+    }
+    ROWS.with(|map| map.borrow_mut().insert(&row, ()));
+    register_accessor(&row, row_accessor());
+    row
+}
+
+fn row_parts(wid: &widget::Widget) -> Option<(button::CheckButton, widget::Widget)> {
+    let row = wid.as_group()?;
+    let check = button::CheckButton::from_dyn_widget_ptr(row.child(0)?.as_widget_ptr() as _)?;
+    Some((check, row.child(1)?))
+}
+
+fn row_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| {
+            let (check, inner) = row_parts(wid)?;
+            if check.value() {
+                Some(format(Some(&props::value_of(&inner)?)))
+            } else {
+                Some(format(None))
+            }
+        },
+        check: |wid, value| match (row_parts(wid), parse(value)) {
+            (Some((_, inner)), Some(value)) => props::is_valid(&inner, &value),
+            (Some(_), None) => true,
+            (None, _) => false,
+        },
+        set: |wid, value| {
+            if let Some((mut check, inner)) = row_parts(wid) {
+                let value = parse(value);
+                check.set_value(value.is_some());
+                layout::set_enabled(&inner, value.is_some());
+                if let Some(value) = value {
+                    props::apply(&inner, &value);
+                }
+            }
+        },
+    }
+}
+
+pub(crate) fn from_row<T: FltkForm>(wid: &widget::Widget) -> Option<Option<T>> {
+    let (check, inner) = row_parts(wid)?;
+    if check.value() {
+        Some(Some(T::from_widget(&inner)?))
+    } else {
+        Some(None)
+    }
+}
+
+pub(crate) fn parse_bool(value: &str) -> Option<Option<bool>> {
+    match parse(value) {
+        Some(value) => value.parse().ok().map(Some),
+        None => Some(None),
+    }
+}
+
+const TRI_STATE: [&str; 3] = [UNSET, "true", "false"];
+
+fn tri_state_index(value: Option<bool>) -> usize {
+    match value {
+        None => 0,
+        Some(true) => 1,
+        Some(false) => 2,
+    }
+}
+
+pub(crate) fn make_tri_state(value: Option<bool>) -> menu::Choice {
+    let mut choice = menu::Choice::default();
+    for item in TRI_STATE {
+        choice.add_choice(item);
+    }
+    choice.set_value(tri_state_index(value) as i32);
+    register_accessor(
+        &choice,
+        ValueAccessor {
+            get: |wid| {
+                let choice = menu::Choice::from_dyn_widget_ptr(wid.as_widget_ptr() as _)?;
+                let value = TRI_STATE.get(choice.value() as usize)?;
+                Some(format(parse(value).as_deref()))
+            },
+            check: |_, value| parse_bool(value).is_some(),
+            set: |wid, value| {
+                if let (Some(mut choice), Some(value)) = (
+                    menu::Choice::from_dyn_widget_ptr(wid.as_widget_ptr() as _),
+                    parse_bool(value),
+                ) {
+                    choice.set_value(tri_state_index(value) as i32);
+                }
+            },
+        },
+    );
+    choice
+}
+
+pub(crate) fn make_tri_state_output(value: Option<bool>) -> output::Output {
+    let mut out = output::Output::default();
+    out.set_value(TRI_STATE[tri_state_index(value)]);
+    out.clear_visible_focus();
+    register_accessor(
+        &out,
+        ValueAccessor {
+            get: |wid| {
+                let out = output::Output::from_dyn_widget_ptr(wid.as_widget_ptr() as _)?;
+                Some(format(parse(&out.value()).as_deref()))
+            },
+            check: |_, value| parse_bool(value).is_some(),
+            set: |wid, value| {
+                if let (Some(mut out), Some(value)) = (
+                    output::Output::from_dyn_widget_ptr(wid.as_widget_ptr() as _),
+                    parse_bool(value),
+                ) {
+                    out.set_value(TRI_STATE[tri_state_index(value)]);
+                }
+            },
+        },
+    );
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_optional_values() {
+        assert_eq!(parse("None"), None);
+        assert_eq!(parse(" None "), None);
+        assert_eq!(parse("Some(None)").as_deref(), Some("None"));
+        assert_eq!(parse("\"None\"").as_deref(), Some("None"));
+        assert_eq!(parse("Some(1)").as_deref(), Some("1"));
+        assert_eq!(parse("1").as_deref(), Some("1"));
+        assert_eq!(parse("Some()").as_deref(), Some(""));
+        assert_eq!(parse("Some([1, 2])").as_deref(), Some("[1, 2]"));
+        assert_eq!(parse("Some(Some(1))").as_deref(), Some("Some(1)"));
+        assert_eq!(parse("\"a, b\"").as_deref(), Some("a, b"));
+        assert_eq!(parse("Some(1").as_deref(), Some("Some(1"));
+    }
+
+    #[test]
+    fn formats_back_what_it_parses() {
+        for value in [None, Some(""), Some("None"), Some("1"), Some("a, (b)")] {
+            assert_eq!(parse(&format(value)).as_deref(), value);
+        }
+    }
+
+    #[test]
+    fn parses_optional_bools() {
+        assert_eq!(parse_bool("None"), Some(None));
+        assert_eq!(parse_bool("true"), Some(Some(true)));
+        assert_eq!(parse_bool("Some(false)"), Some(Some(false)));
+        assert_eq!(parse_bool("\"true\""), Some(Some(true)));
+        assert_eq!(parse_bool("\"None\""), None);
+        assert_eq!(parse_bool("Some(None)"), None);
+        assert_eq!(parse_bool("yes"), None);
+        assert_eq!(parse_bool(""), None);
+    }
+}
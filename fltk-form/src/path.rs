@@ -0,0 +1,259 @@
+//! Path fields, `PathBuf` and `FlPath`, shown as an input followed by a button browsing for
+//! the path with the native file chooser. With `#[form(exists)]`, a path to open must exist and
+//! one to save to must be in an existing directory, an empty path meaning none was chosen.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, notify, register_accessor, FltkForm};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+use std::path::{Path, PathBuf};
+
+/// How the path of a field is chosen and checked, set by
+/// `#[form(filter = "*.toml", directory, save, exists)]`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct PathOptions {
+    /// The patterns of the files shown by the chooser, like `*.toml` or `Images\t*.{png,svg}`
+    pub filter: Option<String>,
+    /// Whether a directory is chosen rather than a file
+    pub directory: bool,
+    /// Whether the path is saved to rather than opened
+    pub save: bool,
+    /// Whether the path must be there to be opened, or be in an existing directory to be saved to
+    pub exists: bool,
+}
+
+impl PathOptions {
+    /// Whether `path` is accepted, any path being unless `exists` is set
+    pub fn accepts(&self, path: &Path) -> bool {
+        if !self.exists || path.as_os_str().is_empty() {
+            return true;
+        }
+        if self.save {
+            match path.parent() {
+                Some(parent) if !parent.as_os_str().is_empty() => parent.is_dir(),
+                _ => true,
+            }
+        } else if self.directory {
+            path.is_dir()
+        } else {
+            path.is_file()
+        }
+    }
+}
+
+thread_local! {
+    static OPTIONS: RefCell<WidgetMap<PathOptions>> = RefCell::new(WidgetMap::new());
+}
+
+/// A path to a file or a directory, chosen with the native file chooser
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FlPath(pub PathBuf);
+
+impl From<PathBuf> for FlPath {
+    fn from(path: PathBuf) -> Self {
+        FlPath(path)
+    }
+}
+
+impl From<&str> for FlPath {
+    fn from(path: &str) -> Self {
+        FlPath(PathBuf::from(path))
+    }
+}
+
+impl AsRef<Path> for FlPath {
+    fn as_ref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl FlPath {
+    /// The widget of a path chosen according to `options`, used by the derived `FltkForm`
+    /// implementations for fields with `#[form(filter = ..., directory, save, exists)]`
+    pub fn generate_path(path: &Path, options: PathOptions) -> Box<dyn WidgetExt> {
+        Box::new(make_path(path, options, true))
+    }
+
+    pub fn view_path(path: &Path, options: PathOptions) -> Box<dyn WidgetExt> {
+        Box::new(make_path(path, options, false))
+    }
+
+    /// Reads back the path of a widget created by `generate_path()` or `view_path()`
+    pub fn path_from_widget(wid: &widget::Widget) -> Option<PathBuf> {
+        props::value_of(wid).map(PathBuf::from)
+    }
+}
+
+impl FltkForm for FlPath {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        Self::generate_path(&self.0, PathOptions::default())
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        Self::view_path(&self.0, PathOptions::default())
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        Some(FlPath(Self::path_from_widget(wid)?))
+    }
+    fn blank() -> Option<Self> {
+        Some(FlPath(PathBuf::new()))
+    }
+}
+
+impl FltkForm for PathBuf {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        FlPath::generate_path(self, PathOptions::default())
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        FlPath::view_path(self, PathOptions::default())
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        FlPath::path_from_widget(wid)
+    }
+    fn blank() -> Option<Self> {
+        Some(PathBuf::new())
+    }
+}
+
+fn make_path(path: &Path, options: PathOptions, editable: bool) -> group::Flex {
+    let mut row = group::Flex::default().row();
+    let mut input = input::Input::default();
+    input.set_value(&path.to_string_lossy());
+    input.set_readonly(!editable);
+    let browse = editable.then(|| {
+        let symbol = if options.save {
+            "@filesave"
+        } else {
+            "@fileopen"
+        };
+        let mut btn = button::Button::default().with_label(symbol);
+        btn.set_tooltip("Browse");
+        btn.clear_visible_focus();
+        btn
+    });
+    row.end();
+    row.set_pad(layout::MARGIN);
+    if let Some(mut browse) = browse {
+        row.fixed(&browse, layout::ROW_HEIGHT);
+        props::mark_container(&browse);
+        browse.set_callback({
+            let options = options.clone();
+            move |_| {
+                // the form may have been made read-only since
+                if input.readonly() {
+                    return;
+                }
+                if let Some(path) = choose(&input.value(), &options) {
+                    input.set_value(&path.to_string_lossy());
+                    if let Some(input) = props::root_of(&input) {
+                        notify::notify(&input);
+                    }
+                }
+            }
+        });
+    }
+    OPTIONS.with(|map| map.borrow_mut().insert(&row, options));
+    register_accessor(&row, path_accessor());
+    row
+}
+
+pub(crate) fn choose_image(current: &str) -> Option<PathBuf> {
+    let options = PathOptions {
+        filter: Some("Images\t*.{png,jpg,jpeg,gif,bmp,svg,xpm}".to_string()),
+        ..PathOptions::default()
+    };
+    choose(current, &options)
+}
+
+fn choose(current: &str, options: &PathOptions) -> Option<PathBuf> {
+    use fltk::dialog::{NativeFileChooser, NativeFileChooserType};
+    let kind = match (options.directory, options.save) {
+        (true, true) => NativeFileChooserType::BrowseSaveDir,
+        (true, false) => NativeFileChooserType::BrowseDir,
+        (false, true) => NativeFileChooserType::BrowseSaveFile,
+        (false, false) => NativeFileChooserType::BrowseFile,
+    };
+    let mut chooser = NativeFileChooser::new(kind);
+    if let Some(filter) = &options.filter {
+        chooser.set_filter(filter);
+    }
+    let current = Path::new(current);
+    let dir = if current.is_dir() {
+        Some(current)
+    } else {
+        current.parent().filter(|dir| dir.is_dir())
+    };
+    if let Some(dir) = dir {
+        chooser.set_directory(&dir).ok();
+    }
+    chooser.show();
+    let path = chooser.filename();
+    (!path.as_os_str().is_empty()).then_some(path)
+}
+
+fn input_of(wid: &widget::Widget) -> Option<input::Input> {
+    let input = wid.as_group()?.child(0)?;
+    input::Input::from_dyn_widget_ptr(input.as_widget_ptr() as _)
+}
+
+fn path_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| input_of(wid).map(|i| i.value()),
+        check: |wid, value| {
+            let options = OPTIONS.with(|map| map.borrow_mut().get(wid));
+            options.is_none_or(|options| options.accepts(Path::new(value)))
+        },
+        set: |wid, value| {
+            if let Some(mut i) = input_of(wid) {
+                i.set_value(value);
+            }
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn options(directory: bool, save: bool, exists: bool) -> PathOptions {
+        PathOptions {
+            filter: None,
+            directory,
+            save,
+            exists,
+        }
+    }
+
+    #[test]
+    fn accepts_any_path_unless_it_must_exist() {
+        let missing = Path::new(env!("CARGO_MANIFEST_DIR")).join("missing/file");
+        for (directory, save) in [(false, false), (true, false), (false, true)] {
+            let opts = options(directory, save, false);
+            assert!(opts.accepts(&missing));
+            assert!(opts.accepts(Path::new("")));
+        }
+    }
+
+    #[test]
+    fn checks_existing_paths() {
+        let dir = Path::new(env!("CARGO_MANIFEST_DIR"));
+        let file = dir.join("Cargo.toml");
+        let missing = dir.join("missing");
+
+        let open = options(false, false, true);
+        assert!(open.accepts(&file));
+        assert!(!open.accepts(dir));
+        assert!(!open.accepts(&missing));
+        assert!(open.accepts(Path::new("")));
+
+        let open_dir = options(true, false, true);
+        assert!(open_dir.accepts(dir));
+        assert!(!open_dir.accepts(&file));
+        assert!(!open_dir.accepts(&missing));
+
+        let save = options(false, true, true);
+        assert!(save.accepts(&missing));
+        assert!(save.accepts(&file));
+        assert!(!save.accepts(&missing.join("file")));
+        assert!(save.accepts(Path::new("relative")));
+    }
+}
@@ -0,0 +1,745 @@
+//! The property engine shared by every `HasProps` implementation.
+//!
+//! Properties are the direct children of the root widget generated by `FltkForm::generate()` or
+//! `FltkForm::view()`. They're looked up through a `PropSource`, either by scanning the root's
+//! children or through a `PropIndex` built once when a `Form` is populated.
+
+use crate::{
+    layout, list, map, notify, option, secret, table, tuple, FltkFormError, FltkFormErrorKind,
+};
+use fltk::{prelude::*, utils::is_ptr_of, *};
+use std::cell::RefCell;
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::str::FromStr;
+
+pub(crate) trait PropSource {
+    fn find(&self, prop: &str) -> Option<widget::Widget>;
+    fn props(&self) -> Vec<widget::Widget>;
+    fn rename(&self, prop: &str, new_name: &str) {
+        if self.find(new_name).is_some() {
+            return;
+        }
+        if let Some(mut wid) = self.find(prop) {
+            wid.set_label(new_name);
+        }
+    }
+}
+
+impl<T: PropSource + ?Sized> PropSource for &T {
+    fn find(&self, prop: &str) -> Option<widget::Widget> {
+        (**self).find(prop)
+    }
+    fn props(&self) -> Vec<widget::Widget> {
+        (**self).props()
+    }
+    fn rename(&self, prop: &str, new_name: &str) {
+        (**self).rename(prop, new_name)
+    }
+}
+
+impl PropSource for Option<widget::Widget> {
+    fn find(&self, prop: &str) -> Option<widget::Widget> {
+        find(self.as_ref()?, prop)
+    }
+    fn props(&self) -> Vec<widget::Widget> {
+        self.as_ref().map(props_of).unwrap_or_default()
+    }
+}
+
+#[derive(Debug, Default)]
+struct Indexed {
+    root: Option<widget::Widget>,
+    map: HashMap<String, widget::Widget>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub(crate) struct PropIndex(Rc<RefCell<Indexed>>);
+
+impl PropIndex {
+    pub(crate) fn rebuild(&self, root: Option<widget::Widget>) {
+        let mut map = HashMap::new();
+        if let Some(root) = root.as_ref() {
+            for child in props_of(root) {
+                let label = child.label();
+                if !label.is_empty() {
+                    map.entry(label).or_insert(child);
+                }
+            }
+        }
+        *self.0.borrow_mut() = Indexed { root, map };
+    }
+
+    pub(crate) fn root(&self) -> Option<widget::Widget> {
+        self.0.borrow().root.clone()
+    }
+}
+
+impl PropSource for PropIndex {
+    fn find(&self, prop: &str) -> Option<widget::Widget> {
+        self.0.borrow().map.get(prop).cloned()
+    }
+    fn props(&self) -> Vec<widget::Widget> {
+        self.root().props()
+    }
+    fn rename(&self, prop: &str, new_name: &str) {
+        let mut inner = self.0.borrow_mut();
+        // the other property would no longer be found by its name
+        if inner.map.contains_key(new_name) {
+            return;
+        }
+        if let Some(mut wid) = inner.map.remove(prop) {
+            wid.set_label(new_name);
+            inner.map.insert(new_name.to_string(), wid);
+        }
+    }
+}
+
+pub(crate) fn root_of(wid: &dyn WidgetExt) -> Option<widget::Widget> {
+    widget::Widget::from_dyn_widget_ptr(wid.as_widget_ptr() as _)
+}
+
+pub(crate) fn generated_root(grp: &dyn WidgetExt) -> Option<widget::Widget> {
+    fn search(grp: &widget::Widget) -> Option<widget::Widget> {
+        let grp = grp.as_group()?;
+        (0..grp.children()).filter_map(|i| grp.child(i)).find_map(
+            |child| match registered_accessor(&child) {
+                Some(_) => Some(child),
+                None => search(&child),
+            },
+        )
+    }
+    let grp = root_of(grp)?;
+    search(&grp).or(Some(grp))
+}
+
+pub(crate) fn props_of(root: &widget::Widget) -> Vec<widget::Widget> {
+    // the widgets of the rows of a table are in a hidden group
+    if let Some(rows) = table::rows_of(root) {
+        return rows;
+    }
+    let mut v = vec![];
+    if let Some(grp) = root.as_group() {
+        for child in table::children_of(&grp) {
+            if is_container(&child) {
+                v.append(&mut props_of(&child));
+            } else {
+                v.push(child);
+            }
+        }
+    }
+    v
+}
+
+/// The widgets of the fields of a generated struct, in declaration order
+pub fn fields_of(root: &widget::Widget) -> Vec<widget::Widget> {
+    props_of(root)
+}
+
+pub(crate) fn invalid_props(root: &widget::Widget) -> Vec<String> {
+    props_of(root)
+        .iter()
+        .filter(|child| !is_valid_field(child))
+        .map(|child| child.label())
+        .collect()
+}
+
+pub(crate) fn is_valid_field(wid: &widget::Widget) -> bool {
+    value_of(wid)
+        .map(|value| is_valid(wid, &value))
+        .unwrap_or(false)
+}
+
+pub(crate) fn find(root: &widget::Widget, prop: &str) -> Option<widget::Widget> {
+    if root.as_group().is_none() {
+        return if root.label() == prop {
+            Some(root.clone())
+        } else {
+            None
+        };
+    }
+    props_of(root)
+        .into_iter()
+        .find(|child| child.label() == prop)
+}
+
+/// How the value of a generated widget is read, validated and written.
+/// `FltkForm` implementations register one for the widgets they create using `register_accessor()`
+#[derive(Clone, Copy)]
+pub struct ValueAccessor {
+    pub get: fn(&widget::Widget) -> Option<String>,
+    pub check: fn(&widget::Widget, &str) -> bool,
+    pub set: fn(&widget::Widget, &str),
+}
+
+impl ValueAccessor {
+    /// The text of an input or output widget `I`, accepting values which parse as `T`
+    pub fn text<I: InputExt + WidgetBase, T: FromStr>() -> Self {
+        Self {
+            get: input_value::<I>,
+            check: parses::<T>,
+            set: set_input_value::<I>,
+        }
+    }
+
+    /// The state of a check button, as "true" or "false"
+    pub fn check_button() -> Self {
+        Self {
+            get: |wid| {
+                button::CheckButton::from_dyn_widget_ptr(wid.as_widget_ptr() as _)
+                    .map(|btn| btn.value().to_string())
+            },
+            check: parses::<bool>,
+            set: |wid, value| {
+                if let Some(mut btn) =
+                    button::CheckButton::from_dyn_widget_ptr(wid.as_widget_ptr() as _)
+                {
+                    btn.set_value(value == "true");
+                }
+            },
+        }
+    }
+
+    /// The selected item of a choice, accepting only existing items
+    pub fn choice() -> Self {
+        Self {
+            get: |wid| menu::Choice::from_dyn_widget_ptr(wid.as_widget_ptr() as _)?.choice(),
+            check: |wid, value| {
+                menu::Choice::from_dyn_widget_ptr(wid.as_widget_ptr() as _)
+                    .map(|choice| choice.find_index(value) != -1)
+                    .unwrap_or(false)
+            },
+            set: |wid, value| {
+                if let Some(mut choice) =
+                    menu::Choice::from_dyn_widget_ptr(wid.as_widget_ptr() as _)
+                {
+                    let idx = choice.find_index(value);
+                    choice.set_value(idx);
+                }
+            },
+        }
+    }
+
+    /// A group of values, each child having its own accessor, written as `[a, b, c]`,
+    /// items which would be split apart being quoted like `["a, b", c]`.
+    /// A written value must have as many items as the group has children.
+    pub fn list() -> Self {
+        Self {
+            get: |wid| {
+                let items: Option<Vec<String>> = props_of(wid).iter().map(value_of).collect();
+                Some(format!("[{}]", join_items(items?)))
+            },
+            check: |wid, value| {
+                let items = split_list(value);
+                let children = props_of(wid);
+                items.len() == children.len()
+                    && children
+                        .iter()
+                        .zip(items.iter())
+                        .all(|(child, item)| is_valid(child, item))
+            },
+            set: |wid, value| {
+                for (child, item) in props_of(wid).iter().zip(split_list(value).iter()) {
+                    apply(child, item);
+                }
+            },
+        }
+    }
+
+    /// The fields of a struct, each child having its own accessor, written as `{a: 1, b: text}`.
+    /// Fields left out of a written value are unchanged.
+    pub fn fields() -> Self {
+        fn field(fields: &[widget::Widget], name: &str) -> Option<widget::Widget> {
+            fields.iter().find(|field| field.label() == name).cloned()
+        }
+        Self {
+            get: |wid| {
+                let fields: Option<Vec<String>> = props_of(wid)
+                    .iter()
+                    .map(|field| {
+                        let value = value_of(field)?;
+                        Some(format!("{}: {}", quote(&field.label()), quote(&value)))
+                    })
+                    .collect();
+                Some(format!("{{{}}}", fields?.join(", ")))
+            },
+            check: |wid, value| {
+                let fields = props_of(wid);
+                split_pairs(value).is_some_and(|pairs| {
+                    pairs.iter().all(|(name, value)| {
+                        field(&fields, name).is_some_and(|field| is_valid(&field, value))
+                    })
+                })
+            },
+            set: |wid, value| {
+                let fields = props_of(wid);
+                for (name, value) in split_pairs(value).unwrap_or_default() {
+                    if let Some(field) = field(&fields, &name) {
+                        apply(&field, &value);
+                    }
+                }
+            },
+        }
+    }
+
+    fn label() -> Self {
+        Self {
+            get: |wid| Some(wid.label()),
+            check: |wid, value| wid.label() == value,
+            set: |_, _| (),
+        }
+    }
+}
+
+fn input_value<I: InputExt + WidgetBase>(wid: &widget::Widget) -> Option<String> {
+    I::from_dyn_widget_ptr(wid.as_widget_ptr() as _).map(|inp| inp.value())
+}
+
+fn set_input_value<I: InputExt + WidgetBase>(wid: &widget::Widget, value: &str) {
+    if let Some(mut inp) = I::from_dyn_widget_ptr(wid.as_widget_ptr() as _) {
+        inp.set_value(value);
+    }
+}
+
+fn parses<T: FromStr>(_wid: &widget::Widget, value: &str) -> bool {
+    value.parse::<T>().is_ok()
+}
+
+pub(crate) fn split_list(value: &str) -> Vec<String> {
+    let value = value.trim();
+    let value = value.strip_prefix('[').unwrap_or(value);
+    let value = value.strip_suffix(']').unwrap_or(value);
+    split_items(value)
+}
+
+pub(crate) fn split_items(value: &str) -> Vec<String> {
+    if value.trim().is_empty() {
+        return vec![];
+    }
+    scan(value, ',').0.into_iter().map(unquote).collect()
+}
+
+pub(crate) fn split_pairs(value: &str) -> Option<Vec<(String, String)>> {
+    let value = value.trim();
+    let value = value.strip_prefix('{').unwrap_or(value);
+    let value = value.strip_suffix('}').unwrap_or(value);
+    split_items(value)
+        .iter()
+        .map(|item| split_pair(item))
+        .collect()
+}
+
+pub(crate) fn split_pair(value: &str) -> Option<(String, String)> {
+    let key = *scan(value, ':').0.first()?;
+    let rest = value.trim_start()[key.len()..]
+        .trim_start()
+        .strip_prefix(':')?;
+    Some((unquote(key), unquote(rest)))
+}
+
+pub(crate) fn join_items<S: AsRef<str>>(items: impl IntoIterator<Item = S>) -> String {
+    items
+        .into_iter()
+        .map(|item| quote(item.as_ref()))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+pub(crate) fn quote(item: &str) -> String {
+    let plain = !item.is_empty()
+        && item.trim() == item
+        && !item.starts_with('"')
+        && [',', ':'].iter().all(|sep| {
+            let (parts, balanced) = scan(item, *sep);
+            balanced && parts.len() == 1
+        });
+    if plain {
+        return item.to_string();
+    }
+    let mut quoted = String::with_capacity(item.len() + 2);
+    quoted.push('"');
+    for c in item.chars() {
+        if c == '"' || c == '\\' {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+pub(crate) fn unquote(item: &str) -> String {
+    let item = item.trim();
+    let mut chars = match item.strip_prefix('"') {
+        Some(inner) => inner.chars(),
+        None => return item.to_string(),
+    };
+    let mut unquoted = String::with_capacity(item.len());
+    while let Some(c) = chars.next() {
+        match c {
+            '\\' => unquoted.extend(chars.next()),
+            // the closing quote must end the item
+            '"' if chars.as_str().is_empty() => return unquoted,
+            '"' => break,
+            c => unquoted.push(c),
+        }
+    }
+    item.to_string()
+}
+
+fn scan(value: &str, sep: char) -> (Vec<&str>, bool) {
+    let mut parts = vec![];
+    let (mut depth, mut start, mut balanced) = (0, 0, true);
+    let (mut quoted, mut escaped) = (false, false);
+    for (i, c) in value.char_indices() {
+        if quoted {
+            match c {
+                _ if escaped => escaped = false,
+                '\\' => escaped = true,
+                '"' => quoted = false,
+                _ => (),
+            }
+            continue;
+        }
+        match c {
+            '"' => quoted = true,
+            '[' | '(' | '{' => depth += 1,
+            ']' | ')' | '}' => {
+                depth -= 1;
+                balanced &= depth >= 0;
+            }
+            c if c == sep && depth == 0 => {
+                parts.push(value[start..i].trim());
+                start = i + c.len_utf8();
+            }
+            _ => (),
+        }
+    }
+    parts.push(value[start..].trim());
+    (parts, balanced && depth == 0 && !quoted)
+}
+
+pub(crate) struct WidgetMap<T>(HashMap<usize, (widget::Widget, T)>);
+
+impl<T: Clone> WidgetMap<T> {
+    pub(crate) fn new() -> Self {
+        Self(HashMap::new())
+    }
+
+    pub(crate) fn insert(&mut self, wid: &dyn WidgetExt, value: T) {
+        if let Some(handle) = root_of(wid) {
+            if self.0.len() % 1024 == 1023 {
+                self.0.retain(|_, (handle, _)| !handle.was_deleted());
+            }
+            self.0.insert(wid.as_widget_ptr() as usize, (handle, value));
+        }
+    }
+
+    pub(crate) fn get(&mut self, wid: &dyn WidgetExt) -> Option<T> {
+        let key = wid.as_widget_ptr() as usize;
+        let entry = self
+            .0
+            .get(&key)
+            .map(|(handle, value)| (handle.was_deleted(), value.clone()));
+        match entry {
+            Some((false, value)) => Some(value),
+            Some((true, _)) => {
+                self.0.remove(&key);
+                None
+            }
+            None => None,
+        }
+    }
+}
+
+thread_local! {
+    static ACCESSORS: RefCell<WidgetMap<ValueAccessor>> = RefCell::new(WidgetMap::new());
+    static CONTAINERS: RefCell<WidgetMap<()>> = RefCell::new(WidgetMap::new());
+}
+
+/// Registers how the value of a generated widget is read and written by `HasProps`
+pub fn register_accessor(wid: &dyn WidgetExt, accessor: ValueAccessor) {
+    ACCESSORS.with(|map| map.borrow_mut().insert(wid, accessor));
+}
+
+fn registered_accessor(wid: &widget::Widget) -> Option<ValueAccessor> {
+    ACCESSORS.with(|map| map.borrow_mut().get(wid))
+}
+
+pub(crate) fn mark_container(wid: &dyn WidgetExt) {
+    CONTAINERS.with(|map| map.borrow_mut().insert(wid, ()));
+}
+
+pub(crate) fn is_container(wid: &widget::Widget) -> bool {
+    CONTAINERS.with(|map| map.borrow_mut().get(wid).is_some())
+}
+
+fn accessor_of(wid: &widget::Widget) -> ValueAccessor {
+    if let Some(accessor) = registered_accessor(wid) {
+        return accessor;
+    }
+    let ptr = wid.as_widget_ptr();
+    if is_ptr_of::<input::IntInput>(ptr) {
+        ValueAccessor::text::<input::IntInput, i64>()
+    } else if is_ptr_of::<input::FloatInput>(ptr) {
+        ValueAccessor::text::<input::FloatInput, f64>()
+    } else if is_ptr_of::<input::Input>(ptr) {
+        ValueAccessor::text::<input::Input, String>()
+    } else if is_ptr_of::<button::CheckButton>(ptr) {
+        ValueAccessor::check_button()
+    } else if is_ptr_of::<menu::Choice>(ptr) {
+        ValueAccessor::choice()
+    } else {
+        ValueAccessor::label()
+    }
+}
+
+/// The current value of a generated widget
+pub fn value_of(wid: &widget::Widget) -> Option<String> {
+    (accessor_of(wid).get)(wid)
+}
+
+pub(crate) fn is_valid(wid: &widget::Widget, value: &str) -> bool {
+    (accessor_of(wid).check)(wid, value)
+}
+
+pub(crate) fn apply(wid: &widget::Widget, value: &str) {
+    (accessor_of(wid).set)(wid, value)
+}
+
+pub(crate) fn find_prop<S: PropSource>(src: &S, prop: &str) -> Option<widget::Widget> {
+    src.find(prop).or_else(|| {
+        if let Some((name, index)) = prop.strip_suffix(']').and_then(|p| p.rsplit_once('[')) {
+            let list = find_prop(src, name).filter(list::is_list)?;
+            if map::is_map(&list) {
+                return map::find_value(&list, index);
+            }
+            return props_of(&list).get(index.parse::<usize>().ok()?).cloned();
+        }
+        let (outer, name) = prop.rsplit_once('.')?;
+        let outer = find_prop(src, outer).filter(|outer| outer.as_group().is_some())?;
+        // the fields of an `Option<Struct>` are those of the struct it holds
+        let outer = option::inner_of(&outer).unwrap_or(outer);
+        find(&outer, name)
+    })
+}
+
+pub(crate) fn get_prop<S: PropSource>(src: &S, prop: &str) -> Option<String> {
+    value_of(&find_prop(src, prop)?)
+}
+
+pub(crate) fn set_prop<S: PropSource>(
+    src: &S,
+    prop: &str,
+    value: &str,
+) -> Result<(), FltkFormError> {
+    let wid = find_prop(src, prop).ok_or(FltkFormError::Internal(
+        FltkFormErrorKind::PropertyInexistent,
+    ))?;
+    if !is_valid(&wid, value) {
+        return Err(FltkFormError::Internal(
+            FltkFormErrorKind::FailedToChangeData,
+        ));
+    }
+    apply(&wid, value);
+    notify::notify(&wid);
+    Ok(())
+}
+
+pub(crate) fn get_props<S: PropSource>(src: &S) -> HashMap<String, String> {
+    secret::redacted(|| get_props_with_secrets(src))
+}
+
+pub(crate) fn get_props_with_secrets<S: PropSource>(src: &S) -> HashMap<String, String> {
+    fn add_items(wid: &widget::Widget, name: &str, props: &mut HashMap<String, String>) {
+        let items: Vec<(String, widget::Widget)> = if map::is_map(wid) {
+            map::entries(wid)
+                .into_iter()
+                .filter_map(|(key, value)| Some((format!("{}[{}]", name, value_of(&key)?), value)))
+                .collect()
+        } else if list::is_list(wid) {
+            props_of(wid)
+                .into_iter()
+                .enumerate()
+                .map(|(i, item)| (format!("{}[{}]", name, i), item))
+                .collect()
+        } else if tuple::is_tuple(wid) {
+            props_of(wid)
+                .into_iter()
+                .map(|item| (format!("{}.{}", name, item.label()), item))
+                .collect()
+        } else {
+            return;
+        };
+        for (name, item) in items {
+            if let Some(value) = value_of(&item) {
+                props.insert(name.clone(), value);
+            }
+            add_items(&item, &name, props);
+        }
+    }
+    let mut temp = HashMap::new();
+    for child in src.props() {
+        let label = child.label();
+        if !label.is_empty() && !temp.contains_key(&label) {
+            if let Some(prop) = value_of(&child) {
+                temp.insert(label.clone(), prop);
+            }
+            add_items(&child, &label, &mut temp);
+        }
+    }
+    temp
+}
+
+pub(crate) fn set_props<S: PropSource>(
+    src: &S,
+    props: &HashMap<String, String>,
+) -> Result<(), FltkFormError> {
+    // a name sorts before the names it's a prefix of, so `list` is set before `list[0]`
+    // and `outer` before `outer.a`
+    let mut props: Vec<(&String, &String)> = props.iter().collect();
+    props.sort();
+    let mut found = vec![];
+    let mut rejected = vec![];
+    for (prop, value) in props {
+        match find_prop(src, prop) {
+            Some(wid) if is_valid(&wid, value) => found.push((wid, value)),
+            _ => rejected.push(prop.clone()),
+        }
+    }
+    if !rejected.is_empty() {
+        rejected.sort();
+        return Err(FltkFormError::RejectedProps(rejected));
+    }
+    for (wid, value) in &found {
+        apply(wid, value);
+    }
+    // once every value is set, so that listeners see the new values of the other fields
+    for (wid, _) in &found {
+        notify::notify(wid);
+    }
+    Ok(())
+}
+
+pub(crate) fn rename_prop<S: PropSource>(src: &S, prop: &str, new_name: &str) {
+    src.rename(prop, new_name);
+    if let Some(wid) = src.find(new_name) {
+        layout::relayout(&wid);
+    }
+}
+
+pub(crate) fn get_widget<S: PropSource>(src: &S, prop: &str) -> Option<Box<dyn WidgetExt>> {
+    find_prop(src, prop).map(|wid| Box::new(wid) as Box<dyn WidgetExt>)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(items: &[&str]) -> Vec<String> {
+        split_list(&format!("[{}]", join_items(items)))
+    }
+
+    #[test]
+    fn quotes_only_items_which_would_not_split_back() {
+        assert_eq!(quote("a"), "a");
+        assert_eq!(quote("[1, 2]"), "[1, 2]");
+        assert_eq!(quote("say \"hi\""), "say \"hi\"");
+        assert_eq!(quote(""), "\"\"");
+        assert_eq!(quote(" a"), "\" a\"");
+        assert_eq!(quote("a, b"), "\"a, b\"");
+        assert_eq!(quote("k: v"), "\"k: v\"");
+        assert_eq!(quote("a]"), "\"a]\"");
+        assert_eq!(quote("\"a\\b\""), "\"\\\"a\\\\b\\\"\"");
+    }
+
+    #[test]
+    fn unquotes_only_whole_quoted_items() {
+        assert_eq!(unquote(" plain "), "plain");
+        assert_eq!(unquote("\"a, b\""), "a, b");
+        assert_eq!(unquote("\"a\\\"b\""), "a\"b");
+        assert_eq!(unquote("\"\""), "");
+        assert_eq!(unquote("\"a\" b"), "\"a\" b");
+        assert_eq!(unquote("\"open"), "\"open");
+    }
+
+    #[test]
+    fn scans_outside_of_brackets_and_quotes() {
+        assert_eq!(
+            scan("a, [b, c], (d, e), {f: g}", ','),
+            (vec!["a", "[b, c]", "(d, e)", "{f: g}"], true)
+        );
+        assert_eq!(scan("\"a, b\", c", ','), (vec!["\"a, b\"", "c"], true));
+        assert_eq!(
+            scan("\"a\\\", b\", c", ','),
+            (vec!["\"a\\\", b\"", "c"], true)
+        );
+        assert!(!scan("a], b", ',').1);
+        assert!(!scan("[a, b", ',').1);
+        assert!(!scan("\"a, b", ',').1);
+    }
+
+    #[test]
+    fn splits_lists() {
+        assert!(split_list("[]").is_empty());
+        assert!(split_list("[ ]").is_empty());
+        assert_eq!(split_list("[\"\"]"), [""]);
+        assert_eq!(split_list("[a, b]"), ["a", "b"]);
+        assert_eq!(split_list("[[1, 2], [3]]"), ["[1, 2]", "[3]"]);
+        assert_eq!(split_list("[[[1], [2, 3]], []]"), ["[[1], [2, 3]]", "[]"]);
+        assert_eq!(split_list("[\"a, b\", c]"), ["a, b", "c"]);
+        assert_eq!(split_list("[Some(1, 2), None]"), ["Some(1, 2)", "None"]);
+    }
+
+    #[test]
+    fn joined_items_split_back() {
+        let cases: &[&[&str]] = &[
+            &[],
+            &[""],
+            &["a", "b"],
+            &["a, b", "c"],
+            &["[1, 2]", "[[3], [4, 5]]", "[]"],
+            &["", " padded ", "a"],
+            &["say \"hi\"", "\"quoted\"", "back\\slash", "\""],
+            &["a]", "b[", "(c", "d}"],
+            &["k: v", "{a: 1, b: 2}"],
+            &["None", "\"None\"", "Some(1, 2)"],
+            &["€uro, ü", "日本"],
+        ];
+        for items in cases {
+            assert_eq!(round_trip(items), *items, "{:?}", items);
+        }
+    }
+
+    #[test]
+    fn splits_pairs() {
+        assert_eq!(
+            split_pairs("{a: 1, b: [2, 3]}"),
+            Some(vec![
+                ("a".to_string(), "1".to_string()),
+                ("b".to_string(), "[2, 3]".to_string())
+            ])
+        );
+        assert_eq!(
+            split_pairs("{\"k, 1\": \"v: 2\", 01: x}"),
+            Some(vec![
+                ("k, 1".to_string(), "v: 2".to_string()),
+                ("01".to_string(), "x".to_string())
+            ])
+        );
+        assert_eq!(split_pairs("{}"), Some(vec![]));
+        assert_eq!(split_pairs("{a: 1, b}"), None);
+    }
+
+    #[test]
+    fn splits_a_pair_on_its_first_colon() {
+        let pair = |k: &str, v: &str| Some((k.to_string(), v.to_string()));
+        assert_eq!(split_pair("a: b: c"), pair("a", "b: c"));
+        assert_eq!(split_pair(" a : b "), pair("a", "b"));
+        assert_eq!(split_pair("\"a:b\": c"), pair("a:b", "c"));
+        assert_eq!(split_pair("[a: b]: {c: d}"), pair("[a: b]", "{c: d}"));
+        assert_eq!(split_pair("é: ü"), pair("é", "ü"));
+        assert_eq!(split_pair("a:"), pair("a", ""));
+        assert_eq!(split_pair("novalue"), None);
+    }
+}
@@ -0,0 +1,135 @@
+//! Secret fields, `FlSecret` and the strings marked `#[form(secret)]`, shown masked in an
+//! `input::SecretInput` next to a button revealing them. Their values are left out of
+//! `get_props()`, along with the values of the lists and structs holding them, but are given
+//! when asked for by name with `get_prop()` or through `Form::get_props_with_secrets()`.
+
+use crate::props::{self, ValueAccessor};
+use crate::{layout, register_accessor, FltkForm};
+use fltk::{prelude::*, *};
+use std::cell::Cell;
+use std::fmt;
+
+thread_local! {
+    static REDACTING: Cell<bool> = const { Cell::new(false) };
+}
+
+/// A password or a key, masked unless revealed and never printed by `Debug`
+#[derive(Clone, Default, PartialEq, Eq, Hash)]
+pub struct FlSecret(pub String);
+
+impl FlSecret {
+    /// The secret itself
+    pub fn expose(&self) -> &str {
+        &self.0
+    }
+
+    /// The widget of a secret, used by the derived `FltkForm` implementations
+    /// for `#[form(secret)]` fields
+    pub fn generate_secret(secret: &str) -> Box<dyn WidgetExt> {
+        Box::new(make_secret(secret, true))
+    }
+
+    pub fn view_secret(secret: &str) -> Box<dyn WidgetExt> {
+        Box::new(make_secret(secret, false))
+    }
+
+    /// Reads back the secret of a widget created by `generate_secret()` or `view_secret()`
+    pub fn secret_from_widget(wid: &widget::Widget) -> Option<String> {
+        input_of(wid).map(|i| i.value())
+    }
+}
+
+impl fmt::Debug for FlSecret {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("FlSecret(\"********\")")
+    }
+}
+
+impl From<String> for FlSecret {
+    fn from(secret: String) -> Self {
+        FlSecret(secret)
+    }
+}
+
+impl From<&str> for FlSecret {
+    fn from(secret: &str) -> Self {
+        FlSecret(secret.to_string())
+    }
+}
+
+impl AsRef<str> for FlSecret {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl FltkForm for FlSecret {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        Self::generate_secret(&self.0)
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        Self::view_secret(&self.0)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        Some(FlSecret(Self::secret_from_widget(wid)?))
+    }
+    fn blank() -> Option<Self> {
+        Some(FlSecret(String::new()))
+    }
+}
+
+pub(crate) fn redacted<R, F: FnOnce() -> R>(f: F) -> R {
+    let prev = REDACTING.with(|redacting| redacting.replace(true));
+    let result = f();
+    REDACTING.with(|redacting| redacting.set(prev));
+    result
+}
+
+fn make_secret(secret: &str, editable: bool) -> group::Flex {
+    let mut row = group::Flex::default().row();
+    let mut input = input::SecretInput::default();
+    input.set_value(secret);
+    input.set_readonly(!editable);
+    let mut reveal = button::ToggleButton::default().with_label("Show");
+    reveal.set_tooltip("Reveal the secret");
+    reveal.clear_visible_focus();
+    row.end();
+    row.set_pad(layout::MARGIN);
+    row.fixed(&reveal, 2 * layout::ROW_HEIGHT);
+    props::mark_container(&reveal);
+    reveal.set_callback(move |reveal| {
+        if reveal.value() {
+            input.set_type(input::InputType::Normal);
+            reveal.set_label("Hide");
+        } else {
+            input.set_type(input::InputType::Secret);
+            reveal.set_label("Show");
+        }
+        input.redraw();
+    });
+    register_accessor(&row, secret_accessor());
+    row
+}
+
+fn input_of(wid: &widget::Widget) -> Option<input::Input> {
+    let input = wid.as_group()?.child(0)?;
+    input::Input::from_dyn_widget_ptr(input.as_widget_ptr() as _)
+}
+
+fn secret_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| {
+            if REDACTING.with(|redacting| redacting.get()) {
+                None
+            } else {
+                input_of(wid).map(|i| i.value())
+            }
+        },
+        check: |_, _| true,
+        set: |wid, value| {
+            if let Some(mut i) = input_of(wid) {
+                i.set_value(value);
+            }
+        },
+    }
+}
@@ -0,0 +1,216 @@
+//! Sets of enum values and bitflags, shown as a group of checkboxes named after the values,
+//! or as a multi-select browser when there are too many values for checkboxes.
+//! Either way the set is written as the list of the names of its values, like `[Read, Write]`.
+
+use crate::props::{self, ValueAccessor};
+#[cfg(feature = "bitflags")]
+use crate::FltkForm;
+use crate::{layout, register_accessor, FormVariants};
+use fltk::{prelude::*, *};
+
+const MAX_CHECKBOXES: usize = 8;
+const COLUMNS: usize = 4;
+const BROWSER_LINES: i32 = 6;
+
+pub(crate) fn make_set(names: &[&str], selected: &[bool], editable: bool) -> Box<dyn WidgetExt> {
+    if names.len() > MAX_CHECKBOXES {
+        Box::new(make_browser(names, selected, editable))
+    } else {
+        Box::new(make_checkboxes(names, selected, editable))
+    }
+}
+
+fn make_checkboxes(names: &[&str], selected: &[bool], editable: bool) -> group::Flex {
+    let mut col = group::Flex::default().column();
+    let mut rows = 0;
+    for (names, selected) in names.chunks(COLUMNS).zip(selected.chunks(COLUMNS)) {
+        let row = group::Flex::default().row();
+        for (name, selected) in names.iter().zip(selected) {
+            let mut check = button::CheckButton::default().with_label(name);
+            check.set_value(*selected);
+            check.clear_visible_focus();
+            register_accessor(&check, ValueAccessor::check_button());
+            if !editable {
+                check.deactivate();
+            }
+        }
+        // keeps the columns aligned on the last row
+        for _ in names.len()..COLUMNS {
+            let filler = frame::Frame::default();
+            props::mark_container(&filler);
+        }
+        row.end();
+        props::mark_container(&row);
+        col.fixed(&row, layout::ROW_HEIGHT);
+        rows += 1;
+    }
+    col.end();
+    col.set_pad(0);
+    let h = (rows * layout::ROW_HEIGHT).max(layout::ROW_HEIGHT);
+    col.resize(col.x(), col.y(), col.w(), h);
+    register_accessor(&col, checkboxes_accessor());
+    col
+}
+
+fn make_browser(names: &[&str], selected: &[bool], editable: bool) -> browser::MultiBrowser {
+    let mut b = browser::MultiBrowser::default();
+    for (i, (name, selected)) in names.iter().zip(selected).enumerate() {
+        b.add(name);
+        if *selected {
+            b.select(i as i32 + 1);
+        }
+    }
+    b.set_size(b.w(), BROWSER_LINES * b.text_size() * 3 / 2);
+    register_accessor(&b, browser_accessor());
+    if !editable {
+        b.deactivate();
+    }
+    b
+}
+
+fn parse(known: &[String], value: &str) -> Option<Vec<String>> {
+    props::split_list(value)
+        .into_iter()
+        .map(|name| known.contains(&name).then_some(name))
+        .collect()
+}
+
+fn checkboxes_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| {
+            let names: Vec<String> = props::props_of(wid)
+                .iter()
+                .filter(|check| props::value_of(check).as_deref() == Some("true"))
+                .map(|check| check.label())
+                .collect();
+            Some(format!("[{}]", names.join(", ")))
+        },
+        check: |wid, value| {
+            let known: Vec<String> = props::props_of(wid).iter().map(|c| c.label()).collect();
+            parse(&known, value).is_some()
+        },
+        set: |wid, value| {
+            let checks = props::props_of(wid);
+            let known: Vec<String> = checks.iter().map(|c| c.label()).collect();
+            if let Some(names) = parse(&known, value) {
+                for check in &checks {
+                    let selected = names.contains(&check.label());
+                    props::apply(check, &selected.to_string());
+                }
+            }
+        },
+    }
+}
+
+fn items_of(b: &browser::MultiBrowser) -> Vec<String> {
+    (1..=b.size()).filter_map(|line| b.text(line)).collect()
+}
+
+fn browser_accessor() -> ValueAccessor {
+    fn browser(wid: &widget::Widget) -> Option<browser::MultiBrowser> {
+        browser::MultiBrowser::from_dyn_widget_ptr(wid.as_widget_ptr() as _)
+    }
+    ValueAccessor {
+        get: |wid| {
+            let b = browser(wid)?;
+            let names: Vec<String> = (1..=b.size())
+                .filter(|line| b.selected(*line))
+                .filter_map(|line| b.text(line))
+                .collect();
+            Some(format!("[{}]", names.join(", ")))
+        },
+        check: |wid, value| browser(wid).is_some_and(|b| parse(&items_of(&b), value).is_some()),
+        set: |wid, value| {
+            if let Some(mut b) = browser(wid) {
+                let items = items_of(&b);
+                if let Some(names) = parse(&items, value) {
+                    // lines are selected one by one, so the items are added again
+                    b.clear();
+                    for (i, item) in items.iter().enumerate() {
+                        b.add(item);
+                        if names.contains(item) {
+                            b.select(i as i32 + 1);
+                        }
+                    }
+                }
+            }
+        },
+    }
+}
+
+pub(crate) fn selected_names(wid: &widget::Widget) -> Option<Vec<String>> {
+    let value = props::value_of(wid)?;
+    Some(props::split_list(&value))
+}
+
+pub(crate) fn make_enum_set<T: FormVariants>(
+    contains: impl Fn(&T) -> bool,
+    editable: bool,
+) -> Box<dyn WidgetExt> {
+    let variants = T::variants();
+    let names: Vec<&str> = variants.iter().map(|(name, _)| *name).collect();
+    let selected: Vec<bool> = variants.iter().map(|(_, value)| contains(value)).collect();
+    make_set(&names, &selected, editable)
+}
+
+pub(crate) fn enum_set_from_widget<T: FormVariants, C: FromIterator<T>>(
+    wid: &widget::Widget,
+) -> Option<C> {
+    let names = selected_names(wid)?;
+    Some(
+        T::variants()
+            .into_iter()
+            .filter(|(name, _)| names.iter().any(|n| n == name))
+            .map(|(_, value)| value)
+            .collect(),
+    )
+}
+
+/// A bitflags value, shown like a set of enum values with a checkbox per named flag
+#[cfg(feature = "bitflags")]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct FlFlags<T>(pub T);
+
+#[cfg(feature = "bitflags")]
+impl<T> From<T> for FlFlags<T> {
+    fn from(flags: T) -> Self {
+        FlFlags(flags)
+    }
+}
+
+#[cfg(feature = "bitflags")]
+impl<T: bitflags::Flags + Copy> FlFlags<T> {
+    fn make(&self, editable: bool) -> Box<dyn WidgetExt> {
+        let flags: Vec<_> = T::FLAGS.iter().filter(|flag| flag.is_named()).collect();
+        let names: Vec<&str> = flags.iter().map(|flag| flag.name()).collect();
+        let selected: Vec<bool> = flags
+            .iter()
+            .map(|flag| self.0.contains(*flag.value()))
+            .collect();
+        make_set(&names, &selected, editable)
+    }
+}
+
+#[cfg(feature = "bitflags")]
+impl<T: bitflags::Flags + Copy> FltkForm for FlFlags<T> {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        self.make(true)
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        self.make(false)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        let names = selected_names(wid)?;
+        let mut flags = T::empty();
+        for flag in T::FLAGS
+            .iter()
+            .filter(|flag| names.iter().any(|name| name == flag.name()))
+        {
+            flags.insert(*flag.value());
+        }
+        Some(FlFlags(flags))
+    }
+    fn blank() -> Option<Self> {
+        Some(FlFlags(T::empty()))
+    }
+}
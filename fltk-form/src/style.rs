@@ -0,0 +1,153 @@
+use crate::layout::{ROW_HEIGHT, SPACING};
+use crate::utils::color_map_adaptor;
+use fltk::enums::{Color, Font};
+
+/// The fonts, colors and sizes used by a `Form` for the widgets it generates,
+/// including those of nested structs.
+/// Colors can be given by their HTML name or as `#rrggbb`, the current color being kept for
+/// any other text:
+/// ```rust,no_run
+/// use fltk_form::FormStyle;
+///
+/// let style = FormStyle::dark()
+///     .with_label_color("gainsboro")
+///     .with_error_color("#ff6b68");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FormStyle {
+    pub label_font: Font,
+    pub label_size: i32,
+    pub label_color: Color,
+    /// The background of inputs and outputs
+    pub input_color: Color,
+    /// The text of inputs, outputs and choices
+    pub text_color: Color,
+    /// The background of the form and of the other widgets
+    pub background: Color,
+    /// The height of single line fields
+    pub row_height: i32,
+    /// The vertical space between fields
+    pub spacing: i32,
+    /// The background of fields holding an invalid value
+    pub error_color: Color,
+}
+
+impl Default for FormStyle {
+    fn default() -> Self {
+        FormStyle::light()
+    }
+}
+
+impl FormStyle {
+    /// The default fltk colors
+    pub fn light() -> Self {
+        Self {
+            label_font: Font::Helvetica,
+            label_size: 14,
+            label_color: Color::Foreground,
+            input_color: Color::Background2,
+            text_color: Color::Foreground,
+            background: Color::Background,
+            row_height: ROW_HEIGHT,
+            spacing: SPACING,
+            error_color: Color::from_rgb(255, 200, 200),
+        }
+    }
+
+    pub fn dark() -> Self {
+        Self {
+            label_color: Color::from_rgb(220, 220, 220),
+            input_color: Color::from_rgb(60, 63, 65),
+            text_color: Color::from_rgb(220, 220, 220),
+            background: Color::from_rgb(43, 43, 43),
+            error_color: Color::from_rgb(120, 40, 40),
+            ..FormStyle::light()
+        }
+    }
+
+    pub fn with_label_font(mut self, font: Font, size: i32) -> Self {
+        self.label_font = font;
+        self.label_size = size;
+        self
+    }
+
+    pub fn with_label_color(mut self, color: &str) -> Self {
+        self.label_color = parse_color(color).unwrap_or(self.label_color);
+        self
+    }
+
+    pub fn with_input_color(mut self, color: &str) -> Self {
+        self.input_color = parse_color(color).unwrap_or(self.input_color);
+        self
+    }
+
+    pub fn with_text_color(mut self, color: &str) -> Self {
+        self.text_color = parse_color(color).unwrap_or(self.text_color);
+        self
+    }
+
+    pub fn with_background(mut self, color: &str) -> Self {
+        self.background = parse_color(color).unwrap_or(self.background);
+        self
+    }
+
+    pub fn with_error_color(mut self, color: &str) -> Self {
+        self.error_color = parse_color(color).unwrap_or(self.error_color);
+        self
+    }
+
+    pub fn with_row_height(mut self, h: i32) -> Self {
+        self.row_height = h;
+        self
+    }
+
+    pub fn with_spacing(mut self, spacing: i32) -> Self {
+        self.spacing = spacing;
+        self
+    }
+}
+
+pub(crate) fn parse_color(color: &str) -> Option<Color> {
+    let hex = color_map_adaptor(color);
+    // `from_hex_str()` slices the text by bytes and ignores what follows the 6 digits
+    let digits = hex.strip_prefix('#')?;
+    if digits.len() != 6 || !digits.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    Color::from_hex_str(&hex).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_hex_colors_and_names() {
+        assert_eq!(
+            parse_color("#abcdef"),
+            Some(Color::from_rgb(0xab, 0xcd, 0xef))
+        );
+        assert_eq!(
+            parse_color("#ABCDEF"),
+            Some(Color::from_rgb(0xab, 0xcd, 0xef))
+        );
+        assert_eq!(parse_color("Red"), Some(Color::from_rgb(0xff, 0, 0)));
+    }
+
+    #[test]
+    fn rejects_malformed_colors() {
+        for color in [
+            "",
+            "#",
+            "#abcde",
+            "#abcdef0",
+            "#abcdeg",
+            "abcdef",
+            "#abcde€",
+            "#€abcd",
+            "nocolor",
+        ] {
+            assert_eq!(parse_color(color), None, "{}", color);
+        }
+    }
+}
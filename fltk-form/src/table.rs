@@ -0,0 +1,459 @@
+//! `Vec<T>` shown as a table, with a column per field of `T`, through `FlTable<T>` or
+//! `#[form(table)]`. Each row is generated as usual in a hidden group; clicking a cell moves the
+//! widget of its field over the cell to edit it, and puts it back once done.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, make_blank, notify, register_accessor, FltkForm};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::rc::Rc;
+
+/// A `Vec` of structs shown as a table, with a column per field. Cells are edited in place
+/// with the widget of their field, clicking a column header sorts the rows by that column,
+/// and rows can be added or deleted.
+/// ```rust,no_run
+/// #[macro_use]
+/// extern crate fltk_form_derive;
+///
+/// use fltk::{prelude::*, *};
+/// use fltk_form::{FlTable, FltkForm};
+///
+/// #[derive(Debug, Clone, FltkForm)]
+/// pub struct Contact {
+///     name: String,
+///     age: u8,
+/// }
+///
+/// #[derive(Debug, Clone, FltkForm)]
+/// pub struct Book {
+///     contacts: FlTable<Contact>,
+///     #[form(table)] // <-- the same, for a `Vec`
+///     archived: Vec<Contact>,
+/// }
+/// # fn main() {}
+/// ```
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FlTable<T>(pub Vec<T>);
+
+impl<T> From<Vec<T>> for FlTable<T> {
+    fn from(rows: Vec<T>) -> Self {
+        FlTable(rows)
+    }
+}
+
+impl<T: FltkForm> FlTable<T> {
+    /// The table of `rows`, their cells being editable. Rows are added with `T::blank()`,
+    /// the add button being inactive if `T` has none.
+    pub fn generate_rows(rows: &[T]) -> Box<dyn WidgetExt> {
+        Box::new(make_table(
+            rows.len(),
+            |i| rows[i].generate(),
+            make_blank::<T>(),
+            true,
+        ))
+    }
+
+    /// The table of `rows`, shown with the widgets of `FltkForm::view()`
+    pub fn view_rows(rows: &[T]) -> Box<dyn WidgetExt> {
+        Box::new(make_table(
+            rows.len(),
+            |i| rows[i].view(),
+            make_blank::<T>(),
+            false,
+        ))
+    }
+
+    /// Reads the rows back from a table made by `generate_rows()` or `view_rows()`,
+    /// in their current order
+    pub fn rows_from_widget(wid: &widget::Widget) -> Option<Vec<T>> {
+        rows_of(wid)?.iter().map(T::from_widget).collect()
+    }
+}
+
+impl<T: FltkForm> FltkForm for FlTable<T> {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        Self::generate_rows(&self.0)
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        Self::view_rows(&self.0)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        Some(FlTable(Self::rows_from_widget(wid)?))
+    }
+    fn blank() -> Option<Self> {
+        Some(FlTable(Vec::new()))
+    }
+}
+
+struct Edit {
+    editor: widget::Widget,
+    parent: group::Group,
+    index: i32,
+}
+
+#[derive(Default)]
+struct State {
+    edit: Option<Edit>,
+    /// The column the rows are sorted by, and whether the order is ascending
+    sort: Option<(i32, bool)>,
+    editable: bool,
+}
+
+#[derive(Clone)]
+struct TableInfo {
+    outer: group::Flex,
+    table: table::Table,
+    /// The hidden group holding the widgets of the rows
+    store: group::Pack,
+    bar: Option<group::Flex>,
+    columns: Rc<Vec<String>>,
+    state: Rc<RefCell<State>>,
+}
+
+thread_local! {
+    static TABLES: RefCell<WidgetMap<TableInfo>> = RefCell::new(WidgetMap::new());
+    static EDITED: RefCell<WidgetMap<Option<(i32, widget::Widget)>>> =
+        RefCell::new(WidgetMap::new());
+}
+
+fn info_of(wid: &widget::Widget) -> Option<TableInfo> {
+    TABLES.with(|map| map.borrow_mut().get(wid))
+}
+
+pub(crate) fn rows_of(wid: &widget::Widget) -> Option<Vec<widget::Widget>> {
+    Some(children_of(&info_of(wid)?.store))
+}
+
+pub(crate) fn children_of<G: GroupExt>(grp: &G) -> Vec<widget::Widget> {
+    let mut children: Vec<widget::Widget> =
+        (0..grp.children()).filter_map(|i| grp.child(i)).collect();
+    if let Some(Some((index, editor))) = EDITED.with(|map| map.borrow_mut().get(grp)) {
+        if !editor.was_deleted() {
+            children.insert((index.max(0) as usize).min(children.len()), editor);
+        }
+    }
+    children
+}
+
+fn cells_of(row: &widget::Widget) -> Vec<widget::Widget> {
+    if row.as_group().is_some() {
+        props::fields_of(row)
+    } else {
+        vec![row.clone()]
+    }
+}
+
+const VISIBLE_ROWS: i32 = 8;
+
+fn make_table<F: FnMut(usize) -> Box<dyn WidgetExt>>(
+    len: usize,
+    mut make: F,
+    make_row: Option<fn() -> Box<dyn WidgetExt>>,
+    editable: bool,
+) -> group::Flex {
+    let mut outer = group::Flex::default().column();
+    let mut table = table::Table::default();
+    table.end();
+    let bar = editable.then(|| {
+        let mut bar = group::Flex::default().row();
+        frame::Frame::default();
+        let add = button::Button::default().with_label("@+");
+        let del = button::Button::default().with_label("@9+");
+        bar.end();
+        bar.fixed(&add, layout::ROW_HEIGHT);
+        bar.fixed(&del, layout::ROW_HEIGHT);
+        bar
+    });
+    let mut store = group::Pack::default();
+    for i in 0..len {
+        make(i);
+    }
+    // the column names are the labels of the fields of the first row, or of a blank one
+    let template = match store.child(0) {
+        Some(_) => None,
+        None => make_row.and_then(|make_row| props::root_of(&*make_row())),
+    };
+    store.end();
+    outer.end();
+    let columns: Vec<String> = store
+        .child(0)
+        .map(|first| cells_of(&first).iter().map(|cell| cell.label()).collect())
+        .unwrap_or_default();
+    if let Some(template) = template {
+        store.remove(&template);
+        widget::Widget::delete(template);
+    }
+    store.hide();
+
+    let h = layout::ROW_HEIGHT * (len as i32 + 1).clamp(3, VISIBLE_ROWS) + 4;
+    outer.fixed(&table, h);
+    if let Some(bar) = bar.as_ref() {
+        outer.fixed(bar, layout::ROW_HEIGHT);
+    }
+    outer.set_pad(layout::MARGIN);
+    let bar_h = bar
+        .as_ref()
+        .map_or(0, |_| layout::ROW_HEIGHT + layout::MARGIN);
+    outer.resize(outer.x(), outer.y(), outer.w(), h + bar_h);
+
+    table.set_rows(len as i32);
+    table.set_cols(columns.len() as i32);
+    table.set_col_header(true);
+    table.set_row_header(true);
+    table.set_row_header_width(layout::ROW_HEIGHT + 10);
+    table.set_row_height_all(layout::ROW_HEIGHT);
+    table.set_col_header_height(layout::ROW_HEIGHT);
+    table.set_col_resize(true);
+
+    let info = TableInfo {
+        outer: outer.clone(),
+        table: table.clone(),
+        store: store.clone(),
+        bar: bar.clone(),
+        columns: Rc::new(columns),
+        state: Rc::new(RefCell::new(State {
+            editable,
+            ..State::default()
+        })),
+    };
+    TABLES.with(|map| map.borrow_mut().insert(&outer, info.clone()));
+    register_accessor(&outer, ValueAccessor::list());
+
+    table.resize_callback({
+        let columns = info.columns.len() as i32;
+        move |t, _, _, w, _| {
+            if columns > 0 {
+                let avail = w - t.row_header_width() - app::scrollbar_size() - 4;
+                t.set_col_width_all((avail / columns).max(40));
+            }
+        }
+    });
+    table.draw_cell({
+        let info = info.clone();
+        move |t, ctx, row, col, x, y, w, h| match ctx {
+            table::TableContext::ColHeader => {
+                let mut name = info.columns.get(col as usize).cloned().unwrap_or_default();
+                match info.state.borrow().sort {
+                    Some((sorted, true)) if sorted == col => name.push_str(" @-22->"),
+                    Some((sorted, false)) if sorted == col => name.push_str(" @-28->"),
+                    _ => (),
+                }
+                draw_header(&name, x, y, w, h);
+            }
+            table::TableContext::RowHeader => draw_header(&(row + 1).to_string(), x, y, w, h),
+            table::TableContext::Cell => {
+                let value = children_of(&info.store)
+                    .get(row as usize)
+                    .and_then(|row| cells_of(row).get(col as usize).and_then(props::value_of))
+                    .unwrap_or_default();
+                draw_cell(&value, x, y, w, h, t.is_selected(row, col));
+            }
+            _ => (),
+        }
+    });
+    table.set_callback({
+        let info = info.clone();
+        move |t| match t.callback_context() {
+            table::TableContext::Cell => start_edit(&info, t.callback_row(), t.callback_col()),
+            table::TableContext::ColHeader => sort_by(&info, t.callback_col()),
+            _ => finish_edit(&info),
+        }
+    });
+    if let Some(bar) = bar.as_ref() {
+        if let (Some(mut add), Some(mut del)) = (bar.child(1), bar.child(2)) {
+            match make_row {
+                Some(make_row) => {
+                    add.set_tooltip("Add a row");
+                    add.set_callback({
+                        let info = info.clone();
+                        move |_| add_row(&info, make_row)
+                    });
+                }
+                None => add.deactivate(),
+            }
+            del.set_tooltip("Delete the selected rows");
+            del.set_callback({
+                let info = info.clone();
+                move |_| delete_rows(&info)
+            });
+        }
+    }
+    outer
+}
+
+fn draw_header(txt: &str, x: i32, y: i32, w: i32, h: i32) {
+    draw::push_clip(x, y, w, h);
+    draw::draw_box(
+        enums::FrameType::ThinUpBox,
+        x,
+        y,
+        w,
+        h,
+        enums::Color::FrameDefault,
+    );
+    draw::set_draw_color(enums::Color::Foreground);
+    draw::draw_text2(txt, x, y, w, h, enums::Align::Center);
+    draw::pop_clip();
+}
+
+fn draw_cell(txt: &str, x: i32, y: i32, w: i32, h: i32, selected: bool) {
+    draw::push_clip(x, y, w, h);
+    draw::set_draw_color(if selected {
+        enums::Color::Selection.lighter()
+    } else {
+        enums::Color::Background2
+    });
+    draw::draw_rectf(x, y, w, h);
+    draw::set_draw_color(enums::Color::Foreground);
+    draw::draw_text2(txt, x + 4, y, w - 8, h, enums::Align::Left);
+    draw::set_draw_color(enums::Color::Light2);
+    draw::draw_rect(x, y, w, h);
+    draw::pop_clip();
+}
+
+fn start_edit(info: &TableInfo, row: i32, col: i32) {
+    finish_edit(info);
+    if !info.state.borrow().editable {
+        return;
+    }
+    let cell = info
+        .store
+        .child(row)
+        .and_then(|row| cells_of(&row).get(col as usize).cloned());
+    let (mut editor, parent) = match cell.and_then(|cell| Some((cell.clone(), cell.parent()?))) {
+        Some(cell) => cell,
+        None => return,
+    };
+    let (x, y, w, h) = match info.table.find_cell(table::TableContext::Cell, row, col) {
+        Some(rect) => rect,
+        None => return,
+    };
+    let index = parent.find(&editor);
+    EDITED.with(|map| {
+        map.borrow_mut()
+            .insert(&parent, Some((index, editor.clone())))
+    });
+    let mut table = info.table.clone();
+    table.add(&editor);
+    editor.set_label_type(enums::LabelType::None);
+    editor.resize(x, y, w, h);
+    editor.show();
+    editor.take_focus().ok();
+    table.redraw();
+    info.state.borrow_mut().edit = Some(Edit {
+        editor,
+        parent,
+        index,
+    });
+}
+
+fn finish_edit(info: &TableInfo) {
+    let edit = info.state.borrow_mut().edit.take();
+    if let Some(Edit {
+        mut editor,
+        mut parent,
+        index,
+    }) = edit
+    {
+        EDITED.with(|map| map.borrow_mut().insert(&parent, None));
+        parent.insert(&editor, index);
+        editor.set_label_type(enums::LabelType::Normal);
+        info.table.clone().redraw();
+    }
+}
+
+fn compare(a: &str, b: &str) -> Ordering {
+    match (a.parse::<f64>(), b.parse::<f64>()) {
+        (Ok(a), Ok(b)) => a.partial_cmp(&b).unwrap_or(Ordering::Equal),
+        _ => a.cmp(b),
+    }
+}
+
+fn sort_by(info: &TableInfo, col: i32) {
+    finish_edit(info);
+    let ascending = !matches!(info.state.borrow().sort, Some((sorted, true)) if sorted == col);
+    let mut rows: Vec<(String, widget::Widget)> = (0..info.store.children())
+        .filter_map(|i| info.store.child(i))
+        .map(|row| {
+            let value = cells_of(&row)
+                .get(col as usize)
+                .and_then(props::value_of)
+                .unwrap_or_default();
+            (value, row)
+        })
+        .collect();
+    rows.sort_by(|(a, _), (b, _)| {
+        let order = compare(a, b);
+        if ascending {
+            order
+        } else {
+            order.reverse()
+        }
+    });
+    let mut store = info.store.clone();
+    for (i, (_, row)) in rows.iter().enumerate() {
+        store.insert(row, i as i32);
+    }
+    info.state.borrow_mut().sort = Some((col, ascending));
+    changed(info);
+}
+
+fn add_row(info: &TableInfo, make_row: fn() -> Box<dyn WidgetExt>) {
+    finish_edit(info);
+    let row = layout::with_current(&info.store, make_row);
+    if let Some(mut row) = props::root_of(&*row) {
+        layout::apply_parent_style(&mut row);
+        let watched = props::root_of(&info.outer).is_some_and(|outer| notify::is_notifying(&outer));
+        if watched {
+            notify::watch(&row);
+        }
+    }
+    info.state.borrow_mut().sort = None;
+    changed(info);
+    let last = info.store.children() - 1;
+    let mut table = info.table.clone();
+    table.set_selection(last, 0, last, info.columns.len() as i32 - 1);
+    table.set_top_row(last);
+}
+
+fn delete_rows(info: &TableInfo) {
+    finish_edit(info);
+    let (top, _, bottom, _) = info.table.get_selection();
+    if top < 0 {
+        return;
+    }
+    let rows: Vec<widget::Widget> = (top..=bottom).filter_map(|i| info.store.child(i)).collect();
+    let mut store = info.store.clone();
+    for row in rows {
+        store.remove(&row);
+        widget::Widget::delete(row);
+    }
+    let mut table = info.table.clone();
+    table.unset_selection();
+    changed(info);
+}
+
+fn changed(info: &TableInfo) {
+    let mut table = info.table.clone();
+    table.set_rows(info.store.children());
+    table.redraw();
+    if let Some(outer) = props::root_of(&info.outer) {
+        notify::notify(&outer);
+    }
+}
+
+pub(crate) fn set_editable(wid: &widget::Widget, editable: bool) {
+    if let Some(info) = info_of(wid) {
+        finish_edit(&info);
+        // a table generated by `view()` has no buttons and stays read-only
+        if let Some(mut bar) = info.bar {
+            info.state.borrow_mut().editable = editable;
+            if editable {
+                bar.activate();
+            } else {
+                bar.deactivate();
+            }
+        }
+    }
+}
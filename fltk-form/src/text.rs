@@ -0,0 +1,164 @@
+//! Multiline text fields, `FlText` and the strings marked `#[form(multiline)]`. Lines are wrapped,
+//! and given a maximum length, a counter below the text shows how many characters are used.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, notify, register_accessor, FltkForm};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+use std::fmt;
+
+const TEXT_HEIGHT: i32 = 3 * layout::ROW_HEIGHT;
+const COUNTER_HEIGHT: i32 = 20;
+
+thread_local! {
+    static MAX_LENS: RefCell<WidgetMap<usize>> = RefCell::new(WidgetMap::new());
+}
+
+/// Multiline text, shown in an input wrapping its lines.
+/// `#[form(max_len = 200)]` limits its length, which a counter shows.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct FlText(pub String);
+
+impl From<String> for FlText {
+    fn from(text: String) -> Self {
+        FlText(text)
+    }
+}
+
+impl From<&str> for FlText {
+    fn from(text: &str) -> Self {
+        FlText(text.to_string())
+    }
+}
+
+impl AsRef<str> for FlText {
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl fmt::Display for FlText {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl FlText {
+    /// The widget of multiline text, refusing more than `max_len` characters if given.
+    /// Used by the derived `FltkForm` implementations for `#[form(multiline)]` fields.
+    pub fn generate_text(text: &str, max_len: Option<usize>) -> Box<dyn WidgetExt> {
+        make_text(text, max_len, true)
+    }
+
+    pub fn view_text(text: &str) -> Box<dyn WidgetExt> {
+        make_text(text, None, false)
+    }
+
+    /// Reads back the text of a widget created by `generate_text()` or `view_text()`
+    pub fn text_from_widget(wid: &widget::Widget) -> Option<String> {
+        props::value_of(wid)
+    }
+}
+
+impl FltkForm for FlText {
+    fn generate(&self) -> Box<dyn WidgetExt> {
+        Self::generate_text(&self.0, None)
+    }
+    fn view(&self) -> Box<dyn WidgetExt> {
+        Self::view_text(&self.0)
+    }
+    fn from_widget(wid: &widget::Widget) -> Option<Self> {
+        Some(FlText(Self::text_from_widget(wid)?))
+    }
+    fn blank() -> Option<Self> {
+        Some(FlText(String::new()))
+    }
+}
+
+fn make_input<I: InputExt + WidgetBase + Default>(text: &str) -> I {
+    let mut i = I::default();
+    i.set_value(text);
+    i.set_wrap(true);
+    i.set_size(i.w(), TEXT_HEIGHT);
+    i
+}
+
+fn make_text(text: &str, max_len: Option<usize>, editable: bool) -> Box<dyn WidgetExt> {
+    if !editable {
+        let o = make_input::<output::MultilineOutput>(text);
+        register_accessor(&o, text_accessor());
+        return Box::new(o);
+    }
+    let max_len = match max_len {
+        Some(max_len) => max_len,
+        None => {
+            let i = make_input::<input::MultilineInput>(text);
+            register_accessor(&i, text_accessor());
+            return Box::new(i);
+        }
+    };
+    let mut col = group::Flex::default().column();
+    let mut input = make_input::<input::MultilineInput>(text);
+    input.set_maximum_size(max_len as i32);
+    let counter = frame::Frame::default().with_align(enums::Align::Right | enums::Align::Inside);
+    col.end();
+    col.fixed(&counter, COUNTER_HEIGHT);
+    col.set_pad(0);
+    col.resize(col.x(), col.y(), col.w(), TEXT_HEIGHT + COUNTER_HEIGHT);
+    props::mark_container(&counter);
+    MAX_LENS.with(|map| map.borrow_mut().insert(&col, max_len));
+    register_accessor(&col, text_accessor());
+    update_counter(&col);
+    input.set_trigger(enums::CallbackTrigger::Changed);
+    input.set_callback({
+        let col = col.clone();
+        move |_| {
+            update_counter(&col);
+            if let Some(col) = props::root_of(&col) {
+                notify::notify(&col);
+            }
+        }
+    });
+    notify::mark_notifying(&input);
+    Box::new(col)
+}
+
+fn input_of(wid: &widget::Widget) -> Option<input::Input> {
+    let wid = match wid.as_group() {
+        Some(grp) => grp.child(0)?,
+        None => wid.clone(),
+    };
+    input::Input::from_dyn_widget_ptr(wid.as_widget_ptr() as _)
+}
+
+fn max_len_of(wid: &widget::Widget) -> Option<usize> {
+    MAX_LENS.with(|map| map.borrow_mut().get(wid))
+}
+
+fn update_counter(col: &group::Flex) {
+    let wid = match props::root_of(col) {
+        Some(wid) => wid,
+        None => return,
+    };
+    if let (Some(input), Some(max_len), Some(mut counter)) =
+        (input_of(&wid), max_len_of(&wid), col.child(1))
+    {
+        counter.set_label(&format!("{}/{}", input.value().chars().count(), max_len));
+        counter.redraw();
+    }
+}
+
+fn text_accessor() -> ValueAccessor {
+    ValueAccessor {
+        get: |wid| input_of(wid).map(|i| i.value()),
+        check: |wid, value| max_len_of(wid).is_none_or(|max| value.chars().count() <= max),
+        set: |wid, value| {
+            if let Some(mut i) = input_of(wid) {
+                i.set_value(value);
+            }
+            if let Some(col) = group::Flex::from_dyn_widget_ptr(wid.as_widget_ptr() as _) {
+                update_counter(&col);
+            }
+        },
+    }
+}
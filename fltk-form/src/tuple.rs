@@ -0,0 +1,77 @@
+//! Tuple fields, shown inline on one row. Each element follows its position as sub-label,
+//! which also names it, like `position.0`.
+
+use crate::props::{self, ValueAccessor, WidgetMap};
+use crate::{layout, register_accessor};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+
+const SUB_LABEL_WIDTH: i32 = 20;
+
+thread_local! {
+    static TUPLES: RefCell<WidgetMap<()>> = RefCell::new(WidgetMap::new());
+}
+
+pub(crate) fn is_tuple(wid: &widget::Widget) -> bool {
+    TUPLES.with(|map| map.borrow_mut().get(wid).is_some())
+}
+
+pub(crate) fn make_tuple<F: FnMut(usize) -> Box<dyn WidgetExt>>(
+    len: usize,
+    mut make: F,
+) -> group::Flex {
+    let mut row = group::Flex::default().row();
+    let mut h = layout::ROW_HEIGHT;
+    for i in 0..len {
+        let sub = frame::Frame::default()
+            .with_label(&i.to_string())
+            .with_align(enums::Align::Right | enums::Align::Inside);
+        props::mark_container(&sub);
+        row.fixed(&sub, SUB_LABEL_WIDTH);
+        let item = make(i);
+        if let Some(mut item) = props::root_of(&*item) {
+            // the sub-label shows the position, which names the element
+            item.set_label(&i.to_string());
+            item.set_label_type(enums::LabelType::None);
+            h = h.max(item.h());
+        }
+    }
+    row.end();
+    row.set_pad(layout::MARGIN);
+    row.resize(row.x(), row.y(), row.w(), h);
+    TUPLES.with(|map| map.borrow_mut().insert(&row, ()));
+    register_accessor(&row, tuple_accessor());
+    row
+}
+
+fn tuple_accessor() -> ValueAccessor {
+    fn split(value: &str) -> Vec<String> {
+        let value = value.trim();
+        let value = value.strip_prefix('(').unwrap_or(value);
+        let value = value.strip_suffix(')').unwrap_or(value);
+        // a single element may be written `(a,)`
+        let value = value.trim_end().strip_suffix(',').unwrap_or(value);
+        props::split_items(value)
+    }
+    ValueAccessor {
+        get: |wid| {
+            let items: Option<Vec<String>> =
+                props::props_of(wid).iter().map(props::value_of).collect();
+            Some(format!("({})", props::join_items(items?)))
+        },
+        check: |wid, value| {
+            let items = split(value);
+            let children = props::props_of(wid);
+            items.len() == children.len()
+                && children
+                    .iter()
+                    .zip(items.iter())
+                    .all(|(child, item)| props::is_valid(child, item))
+        },
+        set: |wid, value| {
+            for (child, item) in props::props_of(wid).iter().zip(split(value)) {
+                props::apply(child, &item);
+            }
+        },
+    }
+}
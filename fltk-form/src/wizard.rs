@@ -0,0 +1,249 @@
+use crate::props::{self, WidgetMap};
+use crate::{layout, FltkForm, FltkFormError, Form, FormStyle};
+use fltk::{prelude::*, *};
+use std::cell::RefCell;
+use std::fmt;
+use std::rc::Rc;
+
+type FinishCallback = Rc<RefCell<dyn FnMut(&widget::Widget)>>;
+
+thread_local! {
+    // kept apart from the `Wizard`, which its own buttons hold, so that a callback holding
+    // the wizard doesn't keep itself alive
+    static ON_FINISH: RefCell<WidgetMap<FinishCallback>> = RefCell::new(WidgetMap::new());
+}
+
+/// Shows a struct over several pages with Back, Next and Finish buttons.
+/// Fields are put on pages using `#[form(page = N)]`, fields without a page being on page 0.
+/// The fields of a page are validated before going to the next one.
+/// ```rust,no_run
+/// # #[macro_use] extern crate fltk_form_derive;
+/// use fltk_form::{FltkForm, Wizard};
+///
+/// #[derive(Debug, Clone, FltkForm)]
+/// pub struct Account {
+///     name: String,
+///     #[form(page = 1)]
+///     email: String,
+/// }
+///
+/// let mut wizard = Wizard::default().with_size(400, 300).from_data(Account {
+///     name: String::new(),
+///     email: String::new(),
+/// });
+/// wizard.on_finish(|account: Account| println!("{:?}", account));
+/// ```
+#[derive(Clone)]
+pub struct Wizard {
+    grp: group::Group,
+    form: Form,
+    back: button::Button,
+    next: button::Button,
+    finish: button::ReturnButton,
+}
+
+impl fmt::Debug for Wizard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Wizard")
+            .field("grp", &self.grp)
+            .field("form", &self.form)
+            .finish()
+    }
+}
+
+impl Default for Wizard {
+    fn default() -> Self {
+        Wizard::new(0, 0, 0, 0, None)
+    }
+}
+
+impl Wizard {
+    pub fn new<S: Into<Option<&'static str>>>(x: i32, y: i32, w: i32, h: i32, label: S) -> Self {
+        let grp = group::Group::new(x, y, w, h, label);
+        let form = Form::default();
+        let back = button::Button::default().with_label("@< Back");
+        let next = button::Button::default().with_label("Next @>");
+        let finish = button::ReturnButton::default().with_label("Finish");
+        grp.end();
+        let mut wizard = Self {
+            grp,
+            form,
+            back,
+            next,
+            finish,
+        };
+        wizard.back.set_callback({
+            let wizard = wizard.clone();
+            move |_| {
+                wizard.prev();
+            }
+        });
+        wizard.next.set_callback({
+            let wizard = wizard.clone();
+            move |_| {
+                wizard.next().ok();
+            }
+        });
+        wizard.finish.set_callback({
+            let wizard = wizard.clone();
+            move |_| wizard.finish_pages()
+        });
+        wizard.grp.resize_callback({
+            let wizard = wizard.clone();
+            move |_, _, _, _, _| wizard.place()
+        });
+        wizard.place();
+        wizard.update_buttons();
+        wizard
+    }
+
+    fn place(&self) {
+        let (x, y, w, h) = (self.grp.x(), self.grp.y(), self.grp.w(), self.grp.h());
+        let bar = layout::ROW_HEIGHT + 2 * layout::MARGIN;
+        self.form.clone().resize(x, y, w, (h - bar).max(0));
+        let by = y + h - layout::ROW_HEIGHT - layout::MARGIN;
+        let step = 80 + layout::MARGIN;
+        let bx = x + w - 3 * step;
+        self.back.clone().resize(bx, by, 80, layout::ROW_HEIGHT);
+        self.next
+            .clone()
+            .resize(bx + step, by, 80, layout::ROW_HEIGHT);
+        self.finish
+            .clone()
+            .resize(bx + 2 * step, by, 80, layout::ROW_HEIGHT);
+    }
+
+    pub fn set_data<T: FltkForm>(&mut self, data: T) {
+        self.form
+            .populate(|| layout::with_wizard(|| data.generate()));
+        self.update_buttons();
+    }
+
+    pub fn from_data<T: FltkForm>(mut self, data: T) -> Self {
+        self.set_data(data);
+        self
+    }
+
+    /// Sets the fonts, colors and sizes of the fields, see `Form::set_style()`
+    pub fn set_style(&mut self, style: FormStyle) {
+        self.form.set_style(style);
+    }
+
+    /// Sets the callback receiving the struct when Finish is pressed and every page is valid
+    pub fn on_finish<T: FltkForm + 'static, F: FnMut(T) + 'static>(&mut self, mut cb: F) {
+        let cb: FinishCallback = Rc::new(RefCell::new(move |root: &widget::Widget| {
+            if let Some(data) = T::from_widget(root) {
+                cb(data)
+            }
+        }));
+        ON_FINISH.with(|map| map.borrow_mut().insert(&self.grp, cb));
+    }
+
+    /// Reads the content of every page back into a `T`, `None` if some property holds an invalid value
+    pub fn get_data<T: FltkForm>(&self) -> Option<T> {
+        self.form.get_data()
+    }
+
+    /// The index of the current page
+    pub fn page(&self) -> usize {
+        self.pages()
+            .and_then(|pages| {
+                let current = pages.try_current_widget()?;
+                (0..pages.children()).find(|&i| {
+                    pages
+                        .child(i)
+                        .map(|page| page.as_widget_ptr() == current.as_widget_ptr())
+                        .unwrap_or(false)
+                })
+            })
+            .map(|i| i as usize)
+            .unwrap_or(0)
+    }
+
+    pub fn page_count(&self) -> usize {
+        self.pages()
+            .map(|pages| pages.children() as usize)
+            .unwrap_or(1)
+    }
+
+    /// Goes to the next page if the fields of the current one are valid,
+    /// otherwise they're reported and returned
+    pub fn next(&self) -> Result<(), FltkFormError> {
+        self.check_page()?;
+        if let Some(mut pages) = self.pages() {
+            pages.next();
+        }
+        self.update_buttons();
+        Ok(())
+    }
+
+    /// Goes back to the previous page
+    pub fn prev(&self) {
+        if let Some(mut pages) = self.pages() {
+            pages.prev();
+        }
+        self.update_buttons();
+    }
+
+    fn finish_pages(&self) {
+        if self.check_page().is_err() {
+            return;
+        }
+        if let Some(root) = self.form.root() {
+            if let Err(FltkFormError::RejectedProps(invalid)) = self.form.validate() {
+                dialog::alert_default(&format!("Invalid values for: {}", invalid.join(", ")));
+                return;
+            }
+            let cb = ON_FINISH.with(|map| map.borrow_mut().get(&self.grp));
+            if let Some(cb) = cb {
+                if let Ok(mut cb) = cb.try_borrow_mut() {
+                    cb(&root);
+                }
+            }
+        }
+    }
+
+    fn check_page(&self) -> Result<(), FltkFormError> {
+        let page = match self
+            .pages()
+            .and_then(|pages| props::root_of(&pages.try_current_widget()?))
+        {
+            Some(page) => page,
+            None => return Ok(()),
+        };
+        let invalid = layout::mark_invalid(&page, &self.form.style());
+        if invalid.is_empty() {
+            Ok(())
+        } else {
+            dialog::alert_default(&format!("Invalid values for: {}", invalid.join(", ")));
+            Err(FltkFormError::RejectedProps(invalid))
+        }
+    }
+
+    fn pages(&self) -> Option<group::Wizard> {
+        layout::wizard_of(&self.form.root()?)
+    }
+
+    fn update_buttons(&self) {
+        let (mut back, mut next, mut finish) =
+            (self.back.clone(), self.next.clone(), self.finish.clone());
+        if self.page() == 0 {
+            back.deactivate();
+        } else {
+            back.activate();
+        }
+        if self.page() + 1 >= self.page_count() {
+            next.hide();
+            finish.activate();
+        } else {
+            next.show();
+            finish.deactivate();
+        }
+    }
+
+    pub(crate) fn props_source(&self) -> &props::PropIndex {
+        self.form.props_source()
+    }
+}
+
+fltk::widget_extends!(Wizard, group::Group, grp);